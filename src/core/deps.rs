@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::services;
+use crate::core::settings::AppSettings;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Visiting,
+    Done,
+}
+
+/// Servisler arasındaki `depends_on` ilişkilerinden oluşan bağımlılık grafiği.
+pub struct DependencyGraph {
+    deps: BTreeMap<String, Vec<String>>,
+}
+
+impl DependencyGraph {
+    /// Grafiği kurar; bilinmeyen bağımlılıklar ve döngüler yapılandırma hatası olarak döner.
+    pub fn new(settings: &AppSettings) -> Result<Self, String> {
+        let deps: BTreeMap<String, Vec<String>> = services::all(settings)
+            .iter()
+            .map(|svc| (svc.name().to_string(), settings.service(svc.name()).depends_on))
+            .collect();
+
+        for (name, list) in &deps {
+            if let Some(unknown) = list.iter().find(|dep| !deps.contains_key(dep.as_str())) {
+                return Err(format!("'{}' depends on unknown service '{}'", name, unknown));
+            }
+        }
+
+        let graph = Self { deps };
+        let names: Vec<String> = graph.deps.keys().cloned().collect();
+        graph.visit_all(&names)?;
+        Ok(graph)
+    }
+
+    /// Hedefleri ve tüm bağımlılıklarını, bağımlılıklar önce gelecek şekilde sıralar.
+    pub fn start_order(&self, targets: &[String]) -> Vec<String> {
+        // Döngüler `new` içinde elendiği için burada hata oluşamaz
+        self.visit_all(targets).unwrap_or_else(|_| targets.to_vec())
+    }
+
+    pub fn dependencies(&self, name: &str) -> &[String] {
+        self.deps.get(name).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Servise doğrudan ya da dolaylı olarak bağımlı olanlar; önce durdurulması gerekenden başlayarak.
+    pub fn dependents(&self, name: &str) -> Vec<String> {
+        let dependents: Vec<String> = self.deps
+            .keys()
+            .filter(|svc| svc.as_str() != name && self.start_order(std::slice::from_ref(svc)).iter().any(|dep| dep == name))
+            .cloned()
+            .collect();
+
+        let mut order = self.start_order(&dependents);
+        order.retain(|svc| dependents.contains(svc));
+        order.reverse();
+        order
+    }
+
+    fn visit_all(&self, targets: &[String]) -> Result<Vec<String>, String> {
+        let mut marks = HashMap::new();
+        let mut stack = Vec::new();
+        let mut order = Vec::new();
+
+        for target in targets {
+            self.visit(target, &mut marks, &mut stack, &mut order)?;
+        }
+
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        name: &str,
+        marks: &mut HashMap<String, Mark>,
+        stack: &mut Vec<String>,
+        order: &mut Vec<String>,
+    ) -> Result<(), String> {
+        match marks.get(name) {
+            Some(Mark::Done) => return Ok(()),
+            Some(Mark::Visiting) => {
+                // Döngüyü kullanıcıya tam yoluyla göster: php -> redis -> php
+                let start = stack.iter().position(|svc| svc == name).unwrap_or(0);
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(name.to_string());
+                return Err(format!("dependency cycle: {}", cycle.join(" -> ")));
+            }
+            None => {}
+        }
+
+        marks.insert(name.to_string(), Mark::Visiting);
+        stack.push(name.to_string());
+
+        for dep in self.dependencies(name) {
+            self.visit(dep, marks, stack, order)?;
+        }
+
+        stack.pop();
+        marks.insert(name.to_string(), Mark::Done);
+        order.push(name.to_string());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(services: &str) -> AppSettings {
+        toml::from_str(&format!("language = \"en\"\n[ports]\nphp = 8000\nmysql = 3306\n{}", services)).unwrap()
+    }
+
+    #[test]
+    fn start_order_puts_dependencies_first() {
+        let graph = DependencyGraph::new(&settings(
+            "[services.php]\ndepends_on = [\"mysql\"]\n[services.queue]\ncommand = \"php artisan queue:work\"\ndepends_on = [\"php\"]\n",
+        ))
+        .unwrap();
+
+        assert_eq!(graph.start_order(&["queue".to_string()]), vec!["mysql", "php", "queue"]);
+        assert_eq!(graph.dependents("mysql"), vec!["queue", "php"]);
+    }
+
+    #[test]
+    fn new_reports_cycles_with_their_path() {
+        let result = DependencyGraph::new(&settings(
+            "[services.a]\ncommand = \"true\"\ndepends_on = [\"b\"]\n[services.b]\ncommand = \"true\"\ndepends_on = [\"a\"]\n",
+        ));
+
+        assert_eq!(result.err().as_deref(), Some("dependency cycle: a -> b -> a"));
+    }
+
+    #[test]
+    fn new_reports_self_dependency() {
+        let result = DependencyGraph::new(&settings("[services.php]\ndepends_on = [\"php\"]\n"));
+        assert_eq!(result.err().as_deref(), Some("dependency cycle: php -> php"));
+    }
+
+    #[test]
+    fn new_reports_unknown_dependencies() {
+        let result = DependencyGraph::new(&settings("[services.php]\ndepends_on = [\"redis\"]\n"));
+        assert_eq!(result.err().as_deref(), Some("'php' depends on unknown service 'redis'"));
+    }
+}
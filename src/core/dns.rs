@@ -0,0 +1,209 @@
+use std::io;
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+use tokio::net::UdpSocket;
+
+use crate::core::network;
+use crate::core::proxy;
+use crate::core::settings::AppSettings;
+
+const TYPE_A: u16 = 1;
+const CLASS_IN: u16 = 1;
+const RCODE_FORMAT_ERROR: u8 = 1;
+const RCODE_REFUSED: u8 = 5;
+// Site eklenip silindiğinde tarayıcının önbelleği uzun süre eski cevabı tutmasın
+const TTL: u32 = 60;
+
+/// `fampp serve dns` ile arka planda çalışan DNS döngüsü. Her sorguda config.toml yeniden okunur,
+/// böylece yeni siteler ve alan adları sunucu yeniden başlatılmadan çözülür.
+pub async fn serve(base_path: &Path, bind: &str, port: u16) -> io::Result<()> {
+    let socket = UdpSocket::bind(network::socket_addr(bind, port)).await?;
+    println!("🧭 DNS listening on {} (udp)", network::socket_addr(bind, port));
+
+    let mut settings = AppSettings::read(base_path).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut buf = [0u8; 512];
+    loop {
+        let (len, peer) = socket.recv_from(&mut buf).await?;
+        // Dosya o an hatalıysa (yarım düzenleme) son geçerli ayarlarla devam edilir
+        if let Ok(fresh) = AppSettings::read(base_path) {
+            settings = fresh;
+        }
+        let address: Ipv4Addr = settings.dns.address.parse().unwrap_or(Ipv4Addr::LOCALHOST);
+
+        if let Some(reply) = answer(&buf[..len], &settings, address) {
+            socket.send_to(&reply, peer).await?;
+        }
+    }
+}
+
+/// Bu alan adı FAMPP'ın sorumluluğunda mı? TLD'nin altındaki her ad ve `domain` ile verilen özel adlar.
+pub fn is_local(name: &str, settings: &AppSettings) -> bool {
+    let tld = settings.proxy.tld.trim_matches('.').to_lowercase();
+    let name = name.trim_end_matches('.').to_lowercase();
+
+    name == tld
+        || name.ends_with(&format!(".{}", tld))
+        || proxy::routes(settings).keys().any(|domain| name == *domain || name.ends_with(&format!(".{}", domain)))
+}
+
+// Tek sorulu bir DNS isteğini yanıtlar; başlık bile okunamıyorsa cevap verilmez
+fn answer(query: &[u8], settings: &AppSettings, address: Ipv4Addr) -> Option<Vec<u8>> {
+    if query.len() < 12 || query[2] & 0x80 != 0 {
+        return None;
+    }
+
+    let question = parse_question(query);
+    let rcode = match &question {
+        None => RCODE_FORMAT_ERROR,
+        Some((name, _, _)) if !is_local(name, settings) => RCODE_REFUSED,
+        Some(_) => 0,
+    };
+
+    let mut reply = Vec::with_capacity(64);
+    reply.extend_from_slice(&query[..2]);
+    // QR + sorgunun opcode ve RD bitleri + yetkili cevap (AA)
+    reply.push(0x80 | (query[2] & 0x79) | 0x04);
+    reply.push(rcode);
+
+    let Some((name, end, qtype)) = question else {
+        reply.extend_from_slice(&[0; 8]);
+        return Some(reply);
+    };
+
+    // AAAA ve diğer türler için boş ama başarılı cevap: tarayıcı beklemeden IPv4'e geçer
+    let answers: u16 = if rcode == 0 && qtype == TYPE_A { 1 } else { 0 };
+
+    reply.extend_from_slice(&1u16.to_be_bytes());
+    reply.extend_from_slice(&answers.to_be_bytes());
+    reply.extend_from_slice(&[0; 4]);
+    reply.extend_from_slice(&query[12..end]);
+
+    if answers == 1 {
+        // Adın kendisi yerine sorudaki ada işaretçi (0xC00C)
+        reply.extend_from_slice(&[0xC0, 0x0C]);
+        reply.extend_from_slice(&TYPE_A.to_be_bytes());
+        reply.extend_from_slice(&CLASS_IN.to_be_bytes());
+        reply.extend_from_slice(&TTL.to_be_bytes());
+        reply.extend_from_slice(&4u16.to_be_bytes());
+        reply.extend_from_slice(&address.octets());
+        println!("🧭 {} -> {}", name, address);
+    }
+
+    Some(reply)
+}
+
+// Soru bölümü: alan adı, sorunun bittiği bayt ve sorgu türü
+fn parse_question(query: &[u8]) -> Option<(String, usize, u16)> {
+    if u16::from_be_bytes([query[4], query[5]]) != 1 {
+        return None;
+    }
+
+    let mut labels = Vec::new();
+    let mut pos = 12;
+    loop {
+        let len = *query.get(pos)? as usize;
+        pos += 1;
+        if len == 0 {
+            break;
+        }
+        // Sorularda sıkıştırma işaretçisi beklenmez
+        if len > 63 {
+            return None;
+        }
+        labels.push(String::from_utf8_lossy(query.get(pos..pos + len)?).to_lowercase());
+        pos += len;
+    }
+
+    let qtype = u16::from_be_bytes([*query.get(pos)?, *query.get(pos + 1)?]);
+    query.get(pos + 3)?;
+    Some((labels.join("."), pos + 4, qtype))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPE_AAAA: u16 = 28;
+
+    fn settings() -> AppSettings {
+        toml::from_str(
+            "language = \"en\"\n[ports]\nphp = 8000\nmysql = 3306\n[sites.blog]\nroot = \"/tmp\"\ndomain = \"myblog.local\"\n",
+        )
+        .unwrap()
+    }
+
+    // Kimliği 0xBEEF olan, RD bayraklı tek sorulu sorgu
+    fn query(name: &str, qtype: u16) -> Vec<u8> {
+        let mut query = vec![0xBE, 0xEF, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+        for label in name.split('.') {
+            query.push(label.len() as u8);
+            query.extend_from_slice(label.as_bytes());
+        }
+        query.push(0);
+        query.extend_from_slice(&qtype.to_be_bytes());
+        query.extend_from_slice(&CLASS_IN.to_be_bytes());
+        query
+    }
+
+    #[test]
+    fn parse_question_reads_name_end_and_type() {
+        let query = query("Shop.Test", TYPE_A);
+        assert_eq!(parse_question(&query), Some(("shop.test".to_string(), query.len(), TYPE_A)));
+    }
+
+    #[test]
+    fn parse_question_rejects_truncated_and_multi_question_queries() {
+        let full = query("shop.test", TYPE_A);
+        assert_eq!(parse_question(&full[..full.len() - 2]), None);
+
+        let mut two = full.clone();
+        two[5] = 2;
+        assert_eq!(parse_question(&two), None);
+    }
+
+    #[test]
+    fn answer_resolves_local_names_to_the_address() {
+        let query = query("api.shop.test", TYPE_A);
+        let reply = answer(&query, &settings(), Ipv4Addr::new(127, 0, 0, 1)).unwrap();
+
+        assert_eq!(&reply[..2], &[0xBE, 0xEF]);
+        // QR + RD + AA, hata yok, bir soru ve bir cevap
+        assert_eq!(reply[2], 0x85);
+        assert_eq!(reply[3], 0);
+        assert_eq!(&reply[4..8], &[0, 1, 0, 1]);
+        assert_eq!(&reply[reply.len() - 4..], &[127, 0, 0, 1]);
+    }
+
+    #[test]
+    fn answer_covers_custom_site_domains() {
+        let reply = answer(&query("myblog.local", TYPE_A), &settings(), Ipv4Addr::LOCALHOST).unwrap();
+        assert_eq!(reply[3], 0);
+        assert_eq!(&reply[6..8], &[0, 1]);
+    }
+
+    #[test]
+    fn answer_returns_empty_success_for_aaaa() {
+        let query = query("shop.test", TYPE_AAAA);
+        let reply = answer(&query, &settings(), Ipv4Addr::LOCALHOST).unwrap();
+
+        assert_eq!(reply[3], 0);
+        assert_eq!(&reply[6..8], &[0, 0]);
+        assert_eq!(reply.len(), query.len());
+    }
+
+    #[test]
+    fn answer_refuses_foreign_names() {
+        let reply = answer(&query("example.com", TYPE_A), &settings(), Ipv4Addr::LOCALHOST).unwrap();
+        assert_eq!(reply[3], RCODE_REFUSED);
+        assert_eq!(&reply[6..8], &[0, 0]);
+    }
+
+    #[test]
+    fn answer_ignores_responses_and_short_packets() {
+        let mut response = query("shop.test", TYPE_A);
+        response[2] |= 0x80;
+        assert!(answer(&response, &settings(), Ipv4Addr::LOCALHOST).is_none());
+        assert!(answer(&[0; 8], &settings(), Ipv4Addr::LOCALHOST).is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::network;
+use crate::core::settings::Probe;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+const PROBE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Servisin hazır olup olmadığını tek seferlik yoklar. Hata durumunda nedenini döndürür.
+pub fn check(probe: &Probe, host: &str, port: u16) -> Result<(), String> {
+    match probe {
+        Probe::Tcp { port: probe_port } => {
+            connect(host, probe_port.unwrap_or(port)).map(|_| ())
+        }
+        Probe::Http { path, status } => {
+            let mut stream = connect(host, port)?;
+            let _ = stream.set_read_timeout(Some(PROBE_TIMEOUT));
+
+            let request = format!(
+                "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: fampp-health\r\nConnection: close\r\n\r\n",
+                path, network::socket_addr(host, port)
+            );
+            stream.write_all(request.as_bytes()).map_err(|e| e.to_string())?;
+
+            // Yalnızca durum satırı bizi ilgilendiriyor: "HTTP/1.1 200 OK"
+            let mut head = [0u8; 64];
+            let n = stream.read(&mut head).map_err(|e| e.to_string())?;
+            let line = String::from_utf8_lossy(&head[..n]);
+            let code: u16 = line
+                .split_whitespace()
+                .nth(1)
+                .and_then(|c| c.parse().ok())
+                .ok_or_else(|| "invalid HTTP response".to_string())?;
+
+            if code == *status {
+                Ok(())
+            } else {
+                Err(format!("HTTP {} (expected {})", code, status))
+            }
+        }
+        Probe::None => Ok(()),
+        Probe::Command { run } => {
+            #[cfg(unix)]
+            let mut cmd = {
+                let mut c = Command::new("sh");
+                c.arg("-c").arg(run);
+                c
+            };
+
+            #[cfg(windows)]
+            let mut cmd = {
+                let mut c = Command::new("cmd");
+                c.arg("/C").arg(run);
+                c
+            };
+
+            let status = cmd
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .map_err(|e| e.to_string())?;
+
+            if status.success() {
+                Ok(())
+            } else {
+                Err(format!("command exited with {}", status))
+            }
+        }
+    }
+}
+
+/// Servis hazır olana, süreç ölene ya da süre dolana kadar spinner göstererek bekler.
+pub fn wait_until_ready<F>(
+    label: &str,
+    probe: &Probe,
+    host: &str,
+    port: u16,
+    timeout: Duration,
+    still_alive: F,
+) -> Result<Duration, String>
+where
+    F: Fn() -> bool,
+{
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(ProgressStyle::with_template("{spinner:.cyan} {msg} {elapsed:.dim}").unwrap());
+    pb.set_message(format!("Waiting for {} to become ready...", label.bold()));
+    pb.enable_steady_tick(Duration::from_millis(80));
+
+    let started = Instant::now();
+
+    let result = loop {
+        let last_error = match check(probe, host, port) {
+            Ok(_) => break Ok(started.elapsed()),
+            Err(e) => e,
+        };
+
+        if !still_alive() {
+            break Err("process exited before becoming ready".to_string());
+        }
+
+        if started.elapsed() >= timeout {
+            break Err(format!("not ready after {}s ({})", timeout.as_secs(), last_error));
+        }
+
+        thread::sleep(PROBE_INTERVAL);
+    };
+
+    pb.finish_and_clear();
+    result
+}
+
+// `(host, port)` çözümlemesi IPv6 adreslerini (örn: `::1`) köşeli parantez gerektirmeden kabul eder
+fn connect(host: &str, port: u16) -> Result<TcpStream, String> {
+    let addr = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or_else(|| format!("could not resolve {}", network::socket_addr(host, port)))?;
+    TcpStream::connect_timeout(&addr, PROBE_TIMEOUT).map_err(|e| e.to_string())
+}
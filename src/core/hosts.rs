@@ -0,0 +1,121 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+const BEGIN: &str = "# BEGIN FAMPP (managed by `fampp hosts sync`, do not edit)";
+const END: &str = "# END FAMPP";
+
+/// İşletim sisteminin hosts dosyası.
+pub fn path() -> PathBuf {
+    if cfg!(windows) {
+        PathBuf::from(r"C:\Windows\System32\drivers\etc\hosts")
+    } else {
+        PathBuf::from("/etc/hosts")
+    }
+}
+
+/// FAMPP bloğu; alan adı yoksa boş döner (blok dosyadan kaldırılır).
+pub fn render_block(address: &str, domains: &[String]) -> String {
+    if domains.is_empty() {
+        return String::new();
+    }
+
+    let mut lines = vec![BEGIN.to_string()];
+    lines.extend(domains.iter().map(|domain| format!("{} {}", address, domain)));
+    lines.push(END.to_string());
+    lines.join("\n") + "\n"
+}
+
+/// Dosyadaki eski FAMPP bloğunu yenisiyle değiştirir; bloğun dışındaki satırlara dokunulmaz.
+/// Bloğun satırları ancak eşleşen END bulunduğunda atılır; END'i silinmiş yarım bir blok olduğu gibi kalır.
+pub fn merge(content: &str, block: &str) -> String {
+    let mut kept = Vec::new();
+    // Açılmış ama henüz kapanmamış bloğun satırları (BEGIN dahil)
+    let mut pending: Option<Vec<&str>> = None;
+
+    for line in content.lines() {
+        match line.trim() {
+            l if l.starts_with("# BEGIN FAMPP") => {
+                kept.extend(pending.replace(vec![line]).unwrap_or_default());
+            }
+            END if pending.is_some() => pending = None,
+            _ => match pending.as_mut() {
+                Some(lines) => lines.push(line),
+                None => kept.push(line),
+            },
+        }
+    }
+    kept.extend(pending.unwrap_or_default());
+
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
+    }
+
+    let mut merged = kept.join("\n");
+    if !merged.is_empty() {
+        merged.push('\n');
+    }
+    if !block.is_empty() {
+        merged.push('\n');
+        merged.push_str(block);
+    }
+    merged
+}
+
+/// Hosts dosyasını günceller; yazma izni yoksa (sudo gerekir) hata döner.
+pub fn sync(address: &str, domains: &[String]) -> io::Result<PathBuf> {
+    let hosts = path();
+    let content = fs::read_to_string(&hosts)?;
+    let merged = merge(&content, &render_block(address, domains));
+
+    if merged != content {
+        fs::write(&hosts, merged)?;
+    }
+    Ok(hosts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block() -> String {
+        render_block("127.0.0.1", &["shop.test".to_string()])
+    }
+
+    #[test]
+    fn merge_appends_block_to_file_without_one() {
+        let merged = merge("127.0.0.1 localhost\n", &block());
+        assert_eq!(merged, format!("127.0.0.1 localhost\n\n{}", block()));
+    }
+
+    #[test]
+    fn merge_replaces_existing_block_and_keeps_other_lines() {
+        let content = format!("127.0.0.1 localhost\n{}\n127.0.0.1 old.test\n{}\n::1 localhost\n", BEGIN, END);
+
+        let merged = merge(&content, &block());
+        assert_eq!(merged, format!("127.0.0.1 localhost\n::1 localhost\n\n{}", block()));
+    }
+
+    #[test]
+    fn merge_is_idempotent() {
+        let once = merge("127.0.0.1 localhost\n", &block());
+        assert_eq!(merge(&once, &block()), once);
+    }
+
+    #[test]
+    fn merge_removes_block_when_no_domains_left() {
+        let content = format!("127.0.0.1 localhost\n\n{}", block());
+        assert_eq!(merge(&content, &render_block("127.0.0.1", &[])), "127.0.0.1 localhost\n");
+    }
+
+    #[test]
+    fn merge_keeps_lines_after_unterminated_block() {
+        let content = format!("127.0.0.1 localhost\n{}\n127.0.0.1 old.test\n10.0.0.5 nas\n", BEGIN);
+
+        let merged = merge(&content, &block());
+        assert!(merged.contains("10.0.0.5 nas"));
+        assert!(merged.contains("127.0.0.1 old.test"));
+        assert!(merged.ends_with(&block()));
+        assert_eq!(merge(&merged, &block()), merged);
+    }
+}
@@ -0,0 +1,58 @@
+use colored::{Color, Colorize};
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const PALETTE: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::BrightRed,
+];
+
+/// Birden fazla servisin çıktısını, renkli servis adlarıyla tek terminalde birleştirir.
+pub struct LogMux {
+    width: usize,
+    // Farklı servislerin satırları birbirine karışmasın diye stdout kilidi
+    out: Arc<Mutex<()>>,
+}
+
+impl LogMux {
+    pub fn new(names: &[&str]) -> Self {
+        let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+        Self { width, out: Arc::new(Mutex::new(())) }
+    }
+
+    pub fn color_for(index: usize) -> Color {
+        PALETTE[index % PALETTE.len()]
+    }
+
+    /// Akışı satır satır okuyup hem terminale (önekli) hem de servis log dosyasına yazar.
+    pub fn attach<R: Read + Send + 'static>(&self, name: &str, color: Color, stream: R, log_path: &Path) {
+        let prefix = format!("{:<width$} |", name, width = self.width).color(color).bold().to_string();
+        let out = Arc::clone(&self.out);
+        let mut log_file = OpenOptions::new().create(true).append(true).open(log_path).ok();
+
+        thread::spawn(move || {
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                if let Some(file) = log_file.as_mut() {
+                    let _ = writeln!(file, "{}", line);
+                }
+
+                let _guard = out.lock().unwrap();
+                let mut stdout = io::stdout().lock();
+                let _ = writeln!(stdout, "{} {}", prefix, line);
+            }
+        });
+    }
+
+    /// Servis adıyla öneklenmiş FAMPP bildirimlerini (başladı, çöktü...) basar.
+    pub fn notice(&self, name: &str, color: Color, message: &str) {
+        let _guard = self.out.lock().unwrap();
+        println!("{} {}", format!("{:<width$} |", name, width = self.width).color(color).bold(), message);
+    }
+}
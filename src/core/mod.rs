@@ -1,7 +1,21 @@
 pub mod config;
+pub mod deps;
+pub mod dns;
 pub mod downloader;
 pub mod extractor;
+pub mod health;
+pub mod hosts;
+pub mod logmux;
+pub mod network;
+pub mod phpext;
+pub mod phpini;
 pub mod process;
+pub mod proxy;
 pub mod registry;
+pub mod services;
 pub mod settings;
-pub mod locale;
\ No newline at end of file
+pub mod ssl;
+pub mod stats;
+pub mod status;
+pub mod locale;
+pub mod supervisor;
@@ -0,0 +1,44 @@
+use std::net::IpAddr;
+
+use sysinfo::Networks;
+
+/// Servislerin varsayılan olarak dinlediği adres: yalnızca bu makine.
+pub const LOOPBACK: &str = "127.0.0.1";
+/// `--lan` ile kullanılan adres: tüm ağ arayüzleri.
+pub const ALL_INTERFACES: &str = "0.0.0.0";
+
+pub fn is_wildcard(bind: &str) -> bool {
+    matches!(bind, "0.0.0.0" | "::" | "[::]")
+}
+
+/// Sağlık yoklamalarının bağlanacağı adres; tüm arayüzleri dinleyen servislere loopback üzerinden ulaşılır.
+pub fn probe_host(bind: &str) -> &str {
+    if is_wildcard(bind) { LOOPBACK } else { bind }
+}
+
+/// `host:port` biçimi; IPv6 adresleri köşeli paranteze alınır (örn: `[::1]:8000`).
+pub fn socket_addr(bind: &str, port: u16) -> String {
+    if bind.contains(':') && !bind.starts_with('[') {
+        format!("[{}]:{}", bind, port)
+    } else {
+        format!("{}:{}", bind, port)
+    }
+}
+
+/// Makinenin yerel ağdaki IPv4 adresleri (telefon gibi diğer cihazlardan erişim için).
+pub fn lan_addresses() -> Vec<IpAddr> {
+    let networks = Networks::new_with_refreshed_list();
+
+    let mut addrs: Vec<IpAddr> = networks
+        .values()
+        .flat_map(|data| data.ip_networks().iter().map(|net| net.addr))
+        .filter(|addr| match addr {
+            IpAddr::V4(v4) => !v4.is_loopback() && !v4.is_link_local() && !v4.is_unspecified(),
+            IpAddr::V6(_) => false,
+        })
+        .collect();
+
+    addrs.sort();
+    addrs.dedup();
+    addrs
+}
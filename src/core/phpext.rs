@@ -0,0 +1,166 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::core::phpini;
+
+/// `fampp php ext list` satırı.
+pub struct Extension {
+    pub name: String,
+    pub installed: bool,
+    pub enabled: bool,
+}
+
+/// İndirilen eklenti ikililerinin tutulduğu `~/.fampp/packages/php/ext/` dizini.
+pub fn dir(base_path: &Path) -> PathBuf {
+    base_path.join("packages").join("php").join("ext")
+}
+
+pub fn binary(base_path: &Path, bin_name: &str) -> PathBuf {
+    dir(base_path).join(bin_name)
+}
+
+/// `ext/` dizininde zaten bulunan (indirilmiş ya da elle kopyalanmış) eklenti ikilisi.
+pub fn find(base_path: &Path, name: &str) -> Option<PathBuf> {
+    fs::read_dir(dir(base_path)).ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.file_name().and_then(|f| name_of(&f.to_string_lossy()).map(|n| n == name)).unwrap_or(false))
+}
+
+/// Xdebug ve OPcache gibi Zend eklentileri php.ini'de `zend_extension` ile yüklenmek zorundadır.
+fn directive(name: &str) -> &'static str {
+    match name {
+        "xdebug" | "opcache" => "zend_extension",
+        _ => "extension",
+    }
+}
+
+// "xdebug.so", "php_redis.dll" -> "xdebug", "redis"
+fn name_of(file: &str) -> Option<&str> {
+    let stem = file.strip_suffix(".so").or_else(|| file.strip_suffix(".dll"))?;
+    Some(stem.strip_prefix("php_").unwrap_or(stem))
+}
+
+// Satır `extension=` / `zend_extension=` ise yüklediği eklentinin adı ve yorum satırı olup olmadığı
+fn parse_line(line: &str) -> Option<(&str, bool)> {
+    let trimmed = line.trim_start();
+    let commented = trimmed.starts_with(';');
+    let (key, value) = trimmed.trim_start_matches(';').split_once('=')?;
+
+    if !matches!(key.trim(), "extension" | "zend_extension") {
+        return None;
+    }
+
+    let value = value.trim().trim_matches('"');
+    let file = value.rsplit(['/', '\\']).next().unwrap_or(value);
+    Some((name_of(file).unwrap_or(file), commented))
+}
+
+/// Registry'deki eklentiler ile `ext/` dizinine elle kopyalananların kurulum ve php.ini durumu.
+pub fn list(base_path: &Path, known: &[&str]) -> io::Result<Vec<Extension>> {
+    let ini = fs::read_to_string(phpini::ensure(base_path)?)?;
+    let enabled: Vec<&str> = ini.lines()
+        .filter_map(parse_line)
+        .filter(|(_, commented)| !commented)
+        .map(|(name, _)| name)
+        .collect();
+
+    let installed: Vec<String> = fs::read_dir(dir(base_path))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| name_of(&entry.file_name().to_string_lossy()).map(str::to_string))
+        .collect();
+
+    let mut names: Vec<String> = known.iter().map(|name| name.to_string()).collect();
+    for name in &installed {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+
+    Ok(names.into_iter().map(|name| Extension {
+        installed: installed.contains(&name),
+        enabled: enabled.contains(&name.as_str()),
+        name,
+    }).collect())
+}
+
+/// `ext/` dizinindeki ikilileri yükleyen etkin satırları `;` ile kapatır ve kapatılan eklentilerin adlarını döner.
+/// Bu ikililer tek bir PHP sürümü için derlendiğinden başka sürüme sabitlenmiş siteler onları yükleyemez.
+pub fn without_downloaded(base_path: &Path, content: &str) -> (String, Vec<String>) {
+    let dir = dir(base_path).to_string_lossy().to_string();
+    let mut skipped = Vec::new();
+
+    let lines: Vec<String> = content.lines().map(|line| match parse_line(line) {
+        Some((name, false)) if line.contains(&dir) => {
+            skipped.push(name.to_string());
+            format!(";{}", line)
+        }
+        _ => line.to_string(),
+    }).collect();
+
+    (lines.join("\n") + "\n", skipped)
+}
+
+/// Eklentiyi tam yoluyla php.ini'ye yazar; daha önce kapatılmış satırı varsa onun yerine geçer.
+pub fn enable(base_path: &Path, name: &str, binary: &Path) -> io::Result<PathBuf> {
+    let entry = format!("{}=\"{}\"", directive(name), binary.display());
+    rewrite(base_path, name, Some(entry))
+}
+
+/// Eklentinin satırını `;` ile kapatır; dosya indirilmiş halde kalır.
+pub fn disable(base_path: &Path, name: &str) -> io::Result<PathBuf> {
+    rewrite(base_path, name, None)
+}
+
+fn rewrite(base_path: &Path, name: &str, entry: Option<String>) -> io::Result<PathBuf> {
+    let ini = phpini::ensure(base_path)?;
+    let content = fs::read_to_string(&ini)?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let index = lines.iter().position(|line| parse_line(line).is_some_and(|(ext, _)| ext.eq_ignore_ascii_case(name)));
+
+    match (index, entry) {
+        (Some(index), Some(entry)) => lines[index] = entry,
+        (None, Some(entry)) => lines.push(entry),
+        (Some(index), None) => {
+            if !lines[index].trim_start().starts_with(';') {
+                lines[index] = format!(";{}", lines[index]);
+            }
+        }
+        (None, None) => {}
+    }
+
+    fs::write(&ini, lines.join("\n") + "\n")?;
+    Ok(ini)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_downloaded_comments_out_registry_binaries_only() {
+        let base_path = Path::new("/home/dev/.fampp");
+        let xdebug = binary(base_path, "xdebug.so");
+        let content = format!(
+            "memory_limit = 256M\nzend_extension=\"{}\"\n;extension=\"{}\"\nextension=/usr/lib/php/pdo_mysql.so\n",
+            xdebug.display(),
+            binary(base_path, "redis.so").display()
+        );
+
+        let (ini, skipped) = without_downloaded(base_path, &content);
+        assert_eq!(skipped, vec!["xdebug"]);
+        assert!(ini.contains(&format!(";zend_extension=\"{}\"", xdebug.display())));
+        assert!(ini.contains("\nextension=/usr/lib/php/pdo_mysql.so\n"));
+    }
+
+    #[test]
+    fn parse_line_reads_extension_names() {
+        assert_eq!(parse_line("extension=\"/x/ext/php_redis.dll\""), Some(("redis", false)));
+        assert_eq!(parse_line(";zend_extension = xdebug.so"), Some(("xdebug", true)));
+        assert_eq!(parse_line("memory_limit = 256M"), None);
+    }
+}
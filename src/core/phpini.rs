@@ -0,0 +1,80 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Tüm PHP örneklerinin `-c` ile kullandığı `~/.fampp/config/php/php.ini`.
+pub fn path(base_path: &Path) -> PathBuf {
+    base_path.join("config").join("php").join("php.ini")
+}
+
+/// Registry eklentilerini yükleyemeyen PHP sürümleri için ortak php.ini'den üretilen kopya (örn: `php-8.3.10.ini`).
+pub fn versioned_path(base_path: &Path, version: &str) -> PathBuf {
+    base_path.join("config").join("php").join(format!("php-{}.ini", version))
+}
+
+/// php.ini yoksa geliştirmeye uygun varsayılanlarla oluşturur; varsa kullanıcının dosyasına dokunmaz.
+pub fn ensure(base_path: &Path) -> io::Result<PathBuf> {
+    let ini = path(base_path);
+    if !ini.exists() {
+        if let Some(dir) = ini.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&ini, include_str!("../../templates/php.ini"))?;
+    }
+    Ok(ini)
+}
+
+/// Ayarı günceller: var olan (ya da `;` ile kapatılmış) satırın yerine yazar, yoksa sona ekler.
+pub fn set(base_path: &Path, key: &str, value: &str) -> io::Result<PathBuf> {
+    let ini = ensure(base_path)?;
+    let content = fs::read_to_string(&ini)?;
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let entry = format!("{} = {}", key, value);
+
+    match lines.iter().position(|line| line_key(line).is_some_and(|k| k.eq_ignore_ascii_case(key))) {
+        Some(index) => lines[index] = entry,
+        None => lines.push(entry),
+    }
+
+    fs::write(&ini, lines.join("\n") + "\n")?;
+    Ok(ini)
+}
+
+// "memory_limit = 256M" ya da ";memory_limit=128M" satırındaki anahtar
+fn line_key(line: &str) -> Option<&str> {
+    let line = line.trim_start().trim_start_matches(';').trim_start();
+    let (key, _) = line.split_once('=')?;
+    let key = key.trim();
+    let valid = !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.'));
+    valid.then_some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_key_reads_active_and_commented_settings() {
+        assert_eq!(line_key("memory_limit = 256M"), Some("memory_limit"));
+        assert_eq!(line_key(";opcache.enable=1"), Some("opcache.enable"));
+        assert_eq!(line_key("[PHP]"), None);
+        assert_eq!(line_key("; a comment = not a key"), None);
+    }
+
+    #[test]
+    fn set_replaces_commented_lines_and_appends_new_keys() {
+        let base_path = std::env::temp_dir().join(format!("fampp-ini-{}", std::process::id()));
+        let ini = path(&base_path);
+        fs::create_dir_all(ini.parent().unwrap()).unwrap();
+        fs::write(&ini, "[PHP]\n;memory_limit = 128M\ndisplay_errors = Off\n").unwrap();
+
+        set(&base_path, "memory_limit", "512M").unwrap();
+        set(&base_path, "DISPLAY_ERRORS", "On").unwrap();
+        set(&base_path, "upload_max_filesize", "64M").unwrap();
+        let content = fs::read_to_string(&ini).unwrap();
+        let _ = fs::remove_dir_all(&base_path);
+
+        assert_eq!(content, "[PHP]\nmemory_limit = 512M\nDISPLAY_ERRORS = On\nupload_max_filesize = 64M\n");
+    }
+}
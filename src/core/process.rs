@@ -1,50 +1,150 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fs::{self, OpenOptions};
-use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStderr, ChildStdout, Command, ExitStatus, Stdio};
+use std::sync::Mutex;
 use std::error::Error;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::thread;
 
+// Servisin SIGTERM sonrası kendini kapatması için tanınan süre
+const GRACEFUL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Bir servisin nasıl başlatılacağını tarif eder: ikili dosya, argümanlar, ortam ve çalışma dizini.
+pub struct LaunchSpec {
+    pub bin_path: PathBuf,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub cwd: Option<PathBuf>,
+}
+
+impl LaunchSpec {
+    pub fn new(bin_path: &Path, args: Vec<String>) -> Self {
+        Self {
+            bin_path: bin_path.to_path_buf(),
+            args,
+            env: BTreeMap::new(),
+            cwd: None,
+        }
+    }
+
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.bin_path);
+        cmd.args(&self.args).envs(&self.env);
+        if let Some(cwd) = &self.cwd {
+            cmd.current_dir(cwd);
+        }
+
+        // Her servis kendi süreç grubunda: terminalin Ctrl+C'si doğrudan çocuklara gitmez,
+        // durdururken de grubun tamamına (PID'e eşit PGID) sinyal gönderebiliriz
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+            cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
+        }
+
+        cmd
+    }
+
+    /// Etkin komut satırı (`status -v` için). Ortam değişkenlerinin değerleri (`.env` içindeki şifreler,
+    /// API anahtarları) ne diske yazılır ne de ekrana basılır; yalnızca adları `KEY=***` olarak görünür.
+    pub fn command_line(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(cwd) = &self.cwd {
+            parts.push(format!("cd {} &&", shell_quote(&cwd.to_string_lossy())));
+        }
+        for key in self.env.keys() {
+            parts.push(format!("{}=***", key));
+        }
+        parts.push(shell_quote(&self.bin_path.to_string_lossy()));
+        parts.extend(self.args.iter().map(|a| shell_quote(a)));
+
+        parts.join(" ")
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c)) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// `.env` dosyasını okur: `KEY=VALUE` satırları, `#` yorumları ve isteğe bağlı `export` öneki.
+pub fn read_env_file(path: &Path) -> Result<BTreeMap<String, String>, Box<dyn Error>> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut vars = BTreeMap::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"').and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value);
+            vars.insert(key.trim().to_string(), value.to_string());
+        }
+    }
+
+    Ok(vars)
+}
+
 pub struct ProcessManager {
     logs_dir: PathBuf,
     pub pids_dir: PathBuf,
+    // Bu süreç içinden başlatılan çocuklar; çıkış kodlarını okuyabilmek için saklanır
+    children: Mutex<HashMap<String, Child>>,
 }
 
 impl ProcessManager {
-    pub fn new(base_path: &PathBuf) -> Self {
+    pub fn new(base_path: &Path) -> Self {
         let pids_dir = base_path.join("data").join("pids");
         let logs_dir = base_path.join("logs");
 
         if !pids_dir.exists() { fs::create_dir_all(&pids_dir).unwrap(); }
         if !logs_dir.exists() { fs::create_dir_all(&logs_dir).unwrap(); }
 
-        Self { logs_dir, pids_dir }
+        Self { logs_dir, pids_dir, children: Mutex::new(HashMap::new()) }
     }
 
-    pub fn start(&self, name: &str, bin_path: &PathBuf, args: &[&str]) -> Result<u32, Box<dyn Error>> {
-        
+    pub fn start(&self, name: &str, spec: &LaunchSpec) -> Result<u32, Box<dyn Error>> {
+
         if !self.logs_dir.exists() {
             std::fs::create_dir_all(&self.logs_dir)?;
         }
-        
+
         let log_file_path = self.logs_dir.join(format!("{}.log", name));
 
         let log_file = OpenOptions::new()
             .create(true)
-            .write(true)
             .append(true)
             .open(&log_file_path)?;
 
         let log_file_clone = log_file.try_clone()?;
 
-        let mut child = Command::new(bin_path)
-            .args(args)
+        let mut child = spec.command()
             .stdout(Stdio::from(log_file))
             .stderr(Stdio::from(log_file_clone))
             .spawn()?;
 
         thread::sleep(Duration::from_millis(100));
-        
+
         if let Ok(Some(status)) = child.try_wait() {
             return Err(format!(
                 "Servis anında çöktü (Çıkış kodu: {}). Lütfen 'cargo run -- logs {}' ile hatayı inceleyin.",
@@ -52,36 +152,256 @@ impl ProcessManager {
             ).into());
         }
 
+        self.track(name, spec, child)
+    }
+
+    /// Servisi `fampp up` için ön planda başlatır; stdout/stderr çağırana devredilir.
+    pub fn spawn_attached(&self, name: &str, spec: &LaunchSpec) -> Result<(u32, ChildStdout, ChildStderr), Box<dyn Error>> {
+        let mut child = spec.command()
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stdout = child.stdout.take().ok_or("stdout could not be captured")?;
+        let stderr = child.stderr.take().ok_or("stderr could not be captured")?;
+
+        let pid = self.track(name, spec, child)?;
+        Ok((pid, stdout, stderr))
+    }
+
+    fn track(&self, name: &str, spec: &LaunchSpec, child: Child) -> Result<u32, Box<dyn Error>> {
         let pid = child.id();
 
         if !self.pids_dir.exists() {
             std::fs::create_dir_all(&self.pids_dir)?;
         }
-        
+
         let pid_file = self.pids_dir.join(format!("{}.pid", name));
         std::fs::write(&pid_file, pid.to_string())?;
+        std::fs::write(self.pids_dir.join(format!("{}.cmd", name)), spec.command_line())?;
+
+        self.children.lock().unwrap().insert(name.to_string(), child);
 
         Ok(pid)
     }
 
-    pub fn stop(&self, name: &str) -> Result<(), Box<dyn Error>> {
-        let pid_file = self.pids_dir.join(format!("{}.pid", name));
-        
-        if !pid_file.exists() {
-            return Err(format!("Service '{}' is not currently running.", name).into());
-        }
+    /// Servisi önce nazikçe (varsayılan SIGTERM) kapatmayı dener, süre dolarsa SIGKILL ile sonlandırır.
+    pub fn stop(&self, name: &str, signal: &str) -> Result<(), Box<dyn Error>> {
+        let pid = self.read_pid(name)
+            .ok_or_else(|| format!("Service '{}' is not currently running.", name))?;
+
+        // PID dosyasını önce siliyoruz ki süpervizör bu kapanışı bir çökme sanmasın
+        self.clear(name);
 
-        let pid_str = std::fs::read_to_string(&pid_file)?;
-        let pid = pid_str.trim();
+        self.terminate(name, pid, signal, GRACEFUL_TIMEOUT);
+
+        Ok(())
+    }
+
+    /// Çalışan servise yapılandırmasını yeniden okuması için sinyal gönderir (örn: HUP, USR2).
+    pub fn reload(&self, name: &str, signal: &str) -> Result<(), Box<dyn Error>> {
+        let pid = self.read_pid(name)
+            .ok_or_else(|| format!("Service '{}' is not currently running.", name))?;
 
         #[cfg(unix)]
-        let _ = Command::new("kill").arg("-9").arg(pid).output();
+        {
+            let output = Command::new("kill").arg(format!("-{}", signal)).arg(pid.to_string()).output()?;
+            if !output.status.success() {
+                return Err(String::from_utf8_lossy(&output.stderr).trim().to_string().into());
+            }
+            Ok(())
+        }
 
         #[cfg(windows)]
-        let _ = Command::new("taskkill").arg("/F").arg("/PID").arg(pid).output();
+        {
+            let _ = pid;
+            Err(format!("Signal '{}' based reloads are not supported on Windows.", signal).into())
+        }
+    }
+
+    /// Servisin PID, port ve komut satırı kayıtlarını siler.
+    pub fn clear(&self, name: &str) {
+        let _ = fs::remove_file(self.pids_dir.join(format!("{}.pid", name)));
+        let _ = fs::remove_file(self.pids_dir.join(format!("{}.port", name)));
+        let _ = fs::remove_file(self.pids_dir.join(format!("{}.cmd", name)));
+        let _ = fs::remove_file(self.pids_dir.join(format!("{}.bind", name)));
+    }
+
+    /// Servisin başlatıldığı etkin komut satırı.
+    pub fn read_command_line(&self, name: &str) -> Option<String> {
+        fs::read_to_string(self.pids_dir.join(format!("{}.cmd", name))).ok()
+    }
+
+    pub fn log_path(&self, name: &str) -> PathBuf {
+        self.logs_dir.join(format!("{}.log", name))
+    }
 
-        let _ = std::fs::remove_file(pid_file);
+    pub fn read_pid(&self, name: &str) -> Option<u32> {
+        let pid_file = self.pids_dir.join(format!("{}.pid", name));
+        fs::read_to_string(pid_file).ok()?.trim().parse().ok()
+    }
+
+    /// Bu süreç tarafından başlatılmış bir servis sonlandıysa çıkış durumunu döndürür (zombiyi de temizler).
+    pub fn try_wait(&self, name: &str) -> Option<ExitStatus> {
+        let mut children = self.children.lock().unwrap();
+        let status = children.get_mut(name)?.try_wait().ok()??;
+        children.remove(name);
+        Some(status)
+    }
+
+    fn terminate(&self, name: &str, pid: u32, signal: &str, timeout: Duration) {
+        signal_tree(pid, Some(signal));
+
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            // Kendi çocuğumuzsa önce zombiyi topluyoruz, yoksa `kill -0` onu hâlâ canlı sanır
+            let _ = self.try_wait(name);
+            if !tree_alive(pid) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
 
+        signal_tree(pid, None);
+        let _ = self.try_wait(name);
+    }
+
+    pub fn is_running(&self, name: &str) -> bool {
+        self.read_pid(name).is_some_and(is_alive)
+    }
+
+    /// Servisin gerçekte dinlediği portu saklar; `status` ve `restart` bunu kullanır.
+    pub fn save_port(&self, name: &str, port: u16) -> Result<(), Box<dyn Error>> {
+        fs::write(self.pids_dir.join(format!("{}.port", name)), port.to_string())?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub fn read_port(&self, name: &str) -> Option<u16> {
+        let port_file = self.pids_dir.join(format!("{}.port", name));
+        fs::read_to_string(port_file).ok()?.trim().parse().ok()
+    }
+
+    /// Servisin dinlediği adresi saklar; `restart` aynı adresle (örn: `--lan`) geri gelsin diye.
+    pub fn save_bind(&self, name: &str, bind: &str) -> Result<(), Box<dyn Error>> {
+        fs::write(self.pids_dir.join(format!("{}.bind", name)), bind)?;
+        Ok(())
+    }
+
+    pub fn read_bind(&self, name: &str) -> Option<String> {
+        let bind = fs::read_to_string(self.pids_dir.join(format!("{}.bind", name))).ok()?;
+        Some(bind.trim().to_string()).filter(|b| !b.is_empty())
+    }
+}
+
+/// Ana süreç ölse bile geride kalmış çocukları (PHP worker'ları, php-fpm çocukları) zorla temizler.
+pub fn kill_tree(pid: u32) {
+    signal_tree(pid, None);
+}
+
+// Servisler kendi süreç grubunda (Unix) başlatılır; böylece sinyal tüm ağaca gider. Windows'ta ağaç
+// `taskkill /T` ile ebeveyn-çocuk ilişkisinden bulunur. Grup yoksa (eski sürümle başlatılmış servis) yalnızca PID'e gönderilir.
+// `signal` boşsa süreçler zorla (SIGKILL) sonlandırılır; Windows'ta her zaman zorla sonlandırılır.
+fn signal_tree(pid: u32, signal: Option<&str>) {
+    #[cfg(unix)]
+    {
+        let sig = format!("-{}", signal.unwrap_or("KILL"));
+        let group = Command::new("kill")
+            .arg(&sig)
+            .arg("--")
+            .arg(format!("-{}", pid))
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success());
+
+        if !group {
+            let _ = Command::new("kill").arg(&sig).arg(pid.to_string()).stderr(Stdio::null()).status();
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        // Windows'ta Unix sinyalleri yok. `/F` olmadan taskkill yalnızca pencerelere kapanma isteği gönderir;
+        // php.exe ve mysqld.exe gibi penceresiz konsol süreçleri bunu reddeder ve her durdurma zaman aşımını
+        // bekleyip yine zorla kapatmaya düşerdi. Bu yüzden ağaç her zaman `/F` ile kapatılır.
+        let _ = signal;
+        let _ = Command::new("taskkill").arg("/F").arg("/T").arg("/PID").arg(pid.to_string()).output();
+    }
+}
+
+fn tree_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        let group = Command::new("kill")
+            .arg("-0")
+            .arg("--")
+            .arg(format!("-{}", pid))
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success());
+
+        group || is_alive(pid)
+    }
+
+    #[cfg(windows)]
+    {
+        is_alive(pid)
+    }
+}
+
+pub fn is_alive(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        Command::new("kill")
+            .arg("-0")
+            .arg(pid.to_string())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success())
+    }
+
+    #[cfg(windows)]
+    {
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .output()
+            .is_ok_and(|o| String::from_utf8_lossy(&o.stdout).contains(&pid.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_env_file_handles_comments_export_and_quotes() {
+        let path = std::env::temp_dir().join(format!("fampp-env-{}.env", std::process::id()));
+        fs::write(
+            &path,
+            "# local\n\nAPP_ENV=local\nexport DB_HOST = 127.0.0.1\nAPP_NAME=\"My App\"\nSECRET='a=b'\nEMPTY=\nnot a pair\n",
+        )
+        .unwrap();
+
+        let vars = read_env_file(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let expected: BTreeMap<String, String> = [
+            ("APP_ENV", "local"),
+            ("DB_HOST", "127.0.0.1"),
+            ("APP_NAME", "My App"),
+            ("SECRET", "a=b"),
+            ("EMPTY", ""),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        assert_eq!(vars, expected);
+    }
+
+    #[test]
+    fn read_env_file_reports_the_missing_path() {
+        let path = std::env::temp_dir().join("fampp-env-does-not-exist.env");
+        let error = read_env_file(&path).unwrap_err().to_string();
+        assert!(error.starts_with(&path.display().to_string()));
+    }
+}
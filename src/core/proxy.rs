@@ -0,0 +1,380 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::rustls::crypto::aws_lc_rs;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::server::{ClientHello, ResolvesServerCert};
+use tokio_rustls::rustls::sign::CertifiedKey;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+use crate::core::network;
+use crate::core::process::ProcessManager;
+use crate::core::services::PhpService;
+use crate::core::settings::AppSettings;
+use crate::core::ssl;
+
+// İstek başlıkları bundan uzunsa Host başlığını aramaktan vazgeçilir
+const MAX_HEAD: usize = 16 * 1024;
+// config.toml'un değişip değişmediğine bakma sıklığı
+const RELOAD_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Bir alan adının yönlendirildiği backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Upstream {
+    // FAMPP servisi; adresi her bağlantıda servisin .port/.bind dosyalarından okunur
+    Service(String),
+    Address(String),
+}
+
+pub type Routes = BTreeMap<String, Upstream>;
+
+/// Siteler (`<ad>.<tld>` ya da `domain`) ve `[proxy.routes]` girdilerinden alan adı -> backend tablosu.
+pub fn routes(settings: &AppSettings) -> Routes {
+    let mut routes = Routes::new();
+
+    for (name, site) in &settings.sites {
+        routes.insert(site.domain(name, &settings.proxy.tld), Upstream::Service(PhpService::instance_name(name)));
+    }
+
+    for (domain, target) in &settings.proxy.routes {
+        let upstream = if target.contains(':') {
+            Upstream::Address(target.clone())
+        } else {
+            Upstream::Service(target.clone())
+        };
+        routes.insert(domain.to_lowercase(), upstream);
+    }
+
+    routes
+}
+
+// Tam eşleşme yoksa alt alan adları üst alan adının backend'ine düşer (örn: cdn.shop.test -> shop.test)
+fn lookup<'a>(routes: &'a Routes, host: &str) -> Option<(&'a String, &'a Upstream)> {
+    let mut candidate = host;
+    loop {
+        if let Some(found) = routes.get_key_value(candidate) {
+            return Some(found);
+        }
+        candidate = candidate.split_once('.')?.1;
+    }
+}
+
+/// `fampp serve proxy` ile arka planda çalışan proxy döngüsü; `tls_port` verilirse HTTPS de sunulur.
+pub async fn serve(base_path: &Path, bind: &str, port: u16, tls_port: Option<u16>) -> io::Result<()> {
+    let listener = TcpListener::bind(network::socket_addr(bind, port)).await?;
+    let settings = AppSettings::read(base_path).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let table = Arc::new(RwLock::new(routes(&settings)));
+    // Servis backend'lerinin PID/port kayıtları için tüm bağlantılarda tek bir yönetici kullanılır
+    let pm = Arc::new(ProcessManager::new(base_path));
+
+    println!("🔀 Proxy listening on {}", network::socket_addr(bind, port));
+
+    // HTTPS portu açılamazsa (örn: 443 için yetki yok) proxy HTTP ile çalışmaya devam eder
+    if let Some(tls_port) = tls_port {
+        match serve_tls(base_path, bind, tls_port, table.clone(), pm.clone()).await {
+            Ok(_) => println!("🔒 Proxy listening on {} (https)", network::socket_addr(bind, tls_port)),
+            Err(e) => eprintln!("⚠️  HTTPS disabled, could not listen on {}: {}", network::socket_addr(bind, tls_port), e),
+        }
+    }
+
+    print_routes(&table.read().unwrap());
+    tokio::spawn(watch_config(base_path.to_path_buf(), table.clone()));
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let table = table.clone();
+        let pm = pm.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = handle(stream, &pm, &table, "http").await {
+                eprintln!("⚠️  {}", e);
+            }
+        });
+    }
+}
+
+// TLS dinleyicisini açar ve bağlantıları arka planda kabul eder
+async fn serve_tls(
+    base_path: &Path,
+    bind: &str,
+    port: u16,
+    table: Arc<RwLock<Routes>>,
+    pm: Arc<ProcessManager>,
+) -> io::Result<()> {
+    ssl::ensure_ca(base_path).map_err(io::Error::other)?;
+
+    let resolver = CertResolver { base_path: base_path.to_path_buf(), table: table.clone(), cache: Mutex::default() };
+    let mut config = ServerConfig::builder_with_provider(Arc::new(aws_lc_rs::default_provider()))
+        .with_safe_default_protocol_versions()
+        .map_err(io::Error::other)?
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(resolver));
+    // Backend'e ham HTTP/1.1 aktarıldığı için HTTP/2 teklif edilmez
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+    let listener = TcpListener::bind(network::socket_addr(bind, port)).await?;
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+            let acceptor = acceptor.clone();
+            let table = table.clone();
+            let pm = pm.clone();
+
+            tokio::spawn(async move {
+                let result = match acceptor.accept(stream).await {
+                    Ok(tls) => handle(tls, &pm, &table, "https").await,
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    eprintln!("⚠️  {}", e);
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+/// SNI'daki alan adına göre sertifika seçer; sertifika yoksa ya da yenileme zamanı geldiyse o an imzalanır.
+#[derive(Debug)]
+struct CertResolver {
+    base_path: PathBuf,
+    table: Arc<RwLock<Routes>>,
+    // Alan adı -> (sertifika dosyasının değişme zamanı, yüklenmiş sertifika)
+    cache: Mutex<HashMap<String, (SystemTime, Arc<CertifiedKey>)>>,
+}
+
+impl CertResolver {
+    // Sitenin alan adı ve bir seviye alt alan adları aynı sertifikayı (`*.shop.test`) paylaşır
+    fn cert_domain(&self, server_name: &str) -> String {
+        let table = self.table.read().unwrap();
+        match lookup(&table, server_name) {
+            Some((domain, _)) if server_name == domain => domain.clone(),
+            Some((domain, _)) if server_name.split_once('.').is_some_and(|(_, parent)| parent == domain) => domain.clone(),
+            _ => server_name.to_string(),
+        }
+    }
+
+    fn load(&self, domain: &str) -> Result<Arc<CertifiedKey>, String> {
+        let (cert_path, key_path) = ssl::ensure_cert(&self.base_path, domain)?;
+        let modified = fs::metadata(&cert_path).and_then(|m| m.modified()).map_err(|e| e.to_string())?;
+
+        let mut cache = self.cache.lock().unwrap();
+        if let Some((loaded, key)) = cache.get(domain)
+            && *loaded == modified
+        {
+            return Ok(key.clone());
+        }
+
+        let cert = CertificateDer::from_pem_file(&cert_path).map_err(|e| e.to_string())?;
+        let key = PrivateKeyDer::from_pem_file(&key_path).map_err(|e| e.to_string())?;
+        let signer = aws_lc_rs::sign::any_supported_type(&key).map_err(|e| e.to_string())?;
+
+        let certified = Arc::new(CertifiedKey::new(vec![cert], signer));
+        cache.insert(domain.to_string(), (modified, certified.clone()));
+        Ok(certified)
+    }
+}
+
+impl ResolvesServerCert for CertResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let server_name = client_hello.server_name()?.to_lowercase();
+        let domain = self.cert_domain(&server_name);
+
+        match self.load(&domain) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                eprintln!("⚠️  Certificate for {}: {}", domain, e);
+                None
+            }
+        }
+    }
+}
+
+fn print_routes(routes: &Routes) {
+    for (domain, upstream) in routes {
+        let target = match upstream {
+            Upstream::Service(name) => name,
+            Upstream::Address(addr) => addr,
+        };
+        println!("   {} -> {}", domain, target);
+    }
+}
+
+// config.toml değiştiğinde (site eklendi/silindi) tabloyu yeniden kurar; sunucu yeniden başlatılmaz
+async fn watch_config(base_path: PathBuf, table: Arc<RwLock<Routes>>) {
+    let config_file = base_path.join("config.toml");
+    let modified = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut last: Option<SystemTime> = modified(&config_file);
+
+    loop {
+        tokio::time::sleep(RELOAD_INTERVAL).await;
+
+        let current = modified(&config_file);
+        if current == last {
+            continue;
+        }
+        last = current;
+
+        match AppSettings::read(&base_path) {
+            Ok(settings) => {
+                let fresh = routes(&settings);
+                let mut table = table.write().unwrap();
+                if *table != fresh {
+                    println!("🔄 Routes reloaded");
+                    print_routes(&fresh);
+                    *table = fresh;
+                }
+            }
+            Err(e) => eprintln!("⚠️  config.toml could not be read, keeping previous routes: {}", e),
+        }
+    }
+}
+
+/// Tek bir istemci bağlantısı: başlıklardan Host okunur, backend'e bağlanılır ve iki yön birbirine bağlanır.
+/// TLS sonlandırması da aynı fonksiyonu kullanabilsin diye akış türünden bağımsızdır.
+pub async fn handle<S>(mut client: S, pm: &ProcessManager, table: &RwLock<Routes>, scheme: &str) -> io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut head = Vec::with_capacity(4096);
+    let mut buf = [0u8; 4096];
+
+    let end = loop {
+        let n = client.read(&mut buf).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        head.extend_from_slice(&buf[..n]);
+
+        if let Some(end) = head.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        if head.len() > MAX_HEAD {
+            return respond(&mut client, "431 Request Header Fields Too Large", "request headers are too large").await;
+        }
+    };
+
+    let text = String::from_utf8_lossy(&head[..end]).to_string();
+    let request_line = text.lines().next().unwrap_or_default().to_string();
+
+    let Some(host) = host_header(&text) else {
+        return respond(&mut client, "400 Bad Request", "missing Host header").await;
+    };
+
+    let upstream = lookup(&table.read().unwrap(), &host).map(|(_, upstream)| upstream.clone());
+    let Some(upstream) = upstream else {
+        let known: Vec<String> = table.read().unwrap().keys().cloned().collect();
+        let message = format!("no site is configured for {}. Known hosts: {}", host, known.join(", "));
+        return respond(&mut client, "404 Not Found", &message).await;
+    };
+
+    let addr = match &upstream {
+        Upstream::Address(addr) => Some(addr.clone()),
+        Upstream::Service(name) => service_addr(pm, name),
+    };
+
+    let backend = match &addr {
+        Some(addr) => TcpStream::connect(addr).await.ok(),
+        None => None,
+    };
+
+    let Some(mut backend) = backend else {
+        let message = match &upstream {
+            Upstream::Service(name) => format!("{} is not running. Start it with: fampp start {}", name, name),
+            Upstream::Address(addr) => format!("nothing is listening on {}", addr),
+        };
+        return respond(&mut client, "502 Bad Gateway", &message).await;
+    };
+
+    println!("🔀 {} {} -> {}", host, request_line, addr.unwrap_or_default());
+
+    backend.write_all(&forward_head(&head, end, scheme)).await?;
+    tokio::io::copy_bidirectional(&mut client, &mut backend).await?;
+    Ok(())
+}
+
+// Backend'in isteğin HTTPS ile gelip gelmediğini bilmesi için X-Forwarded-Proto eklenir. Başlık yalnızca
+// bağlantının ilk isteğine eklenebildiği için backend'den her yanıttan sonra bağlantıyı kapatması istenir;
+// WebSocket yükseltmelerine (örn: Vite HMR) dokunulmaz.
+fn forward_head(head: &[u8], end: usize, scheme: &str) -> Vec<u8> {
+    let text = String::from_utf8_lossy(&head[..end]);
+    let name = |line: &str| line.split_once(':').map(|(name, _)| name.trim().to_ascii_lowercase()).unwrap_or_default();
+    let upgrade = text.split("\r\n").skip(1).any(|line| name(line) == "upgrade");
+
+    let mut lines: Vec<String> = text
+        .split("\r\n")
+        .enumerate()
+        .filter(|(index, line)| {
+            *index == 0 || match name(line).as_str() {
+                "x-forwarded-proto" => false,
+                "connection" | "keep-alive" | "proxy-connection" => upgrade,
+                _ => true,
+            }
+        })
+        .map(|(_, line)| line.to_string())
+        .collect();
+
+    lines.push(format!("X-Forwarded-Proto: {}", scheme));
+    if !upgrade {
+        lines.push("Connection: close".to_string());
+    }
+
+    let mut forwarded = (lines.join("\r\n") + "\r\n\r\n").into_bytes();
+    forwarded.extend_from_slice(&head[end + 4..]);
+    forwarded
+}
+
+// Çalışan FAMPP servisinin adresi; durmuşsa `None`
+fn service_addr(pm: &ProcessManager, name: &str) -> Option<String> {
+    if !pm.is_running(name) {
+        return None;
+    }
+
+    let port = pm.read_port(name)?;
+    let bind = pm.read_bind(name).unwrap_or_else(|| network::LOOPBACK.to_string());
+    Some(network::socket_addr(network::probe_host(&bind), port))
+}
+
+// "Host: Shop.test:8080" -> "shop.test"
+fn host_header(head: &str) -> Option<String> {
+    let value = head.lines().skip(1).find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim().eq_ignore_ascii_case("host").then(|| value.trim())
+    })?;
+
+    let host = match value.strip_prefix('[') {
+        Some(v6) => v6.split(']').next().unwrap_or(v6),
+        None => value.split(':').next().unwrap_or(value),
+    };
+
+    let host = host.trim_end_matches('.').to_lowercase();
+    (!host.is_empty()).then_some(host)
+}
+
+async fn respond<S>(client: &mut S, status: &str, message: &str) -> io::Result<()>
+where
+    S: AsyncWrite + Unpin,
+{
+    let body = format!("FAMPP proxy: {}\n", message);
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    client.write_all(response.as_bytes()).await?;
+    client.shutdown().await
+}
@@ -1,4 +1,14 @@
 use std::env;
+use std::path::Path;
+
+/// Registry'de tanımlı paketler (`fampp list` bunları listeler).
+pub const PACKAGES: [&str; 6] = ["php", "php-fpm", "mysql", "nginx", "apache", "adminer"];
+
+/// Registry'de önceden derlenmiş hali bulunan PHP eklentileri (`fampp php ext list`).
+pub const PHP_EXTENSIONS: [&str; 5] = ["xdebug", "redis", "imagick", "apcu", "mongodb"];
+
+/// `fampp install php` sürüm belirtilmediğinde kurulan PHP sürümü.
+pub const PHP_VERSION: &str = "8.2.12";
 
 pub struct PackageInfo {
     #[allow(dead_code)]
@@ -7,22 +17,24 @@ pub struct PackageInfo {
     pub bin_name: String,
 }
 
-pub fn get_package_info(package_name: &str, _version: &str) -> Option<PackageInfo> {
+pub fn get_package_info(package_name: &str, version: &str) -> Option<PackageInfo> {
     let os = env::consts::OS;
     let arch = env::consts::ARCH;
 
     match package_name.to_lowercase().as_str() {
         "php" => {
+            // Sitelere özel sürümler aynı kaynaklardan tam sürüm numarasıyla indirilir (örn: 8.3.10)
+            let version = if version == "latest" { PHP_VERSION } else { version };
             let (url, bin_name) = match os {
                 "windows" => (
-                    "https://windows.php.net/downloads/releases/php-8.2.12-nts-Win32-vs16-x64.zip",
+                    format!("https://windows.php.net/downloads/releases/php-{}-nts-Win32-vs16-x64.zip", version),
                     "php.exe"
                 ),
                 "macos" => {
                     let mac_url = if arch == "aarch64" {
-                        "https://dl.static-php.dev/static-php-cli/common/php-8.2.12-cli-macos-aarch64.tar.gz" // Apple Silicon
+                        format!("https://dl.static-php.dev/static-php-cli/common/php-{}-cli-macos-aarch64.tar.gz", version) // Apple Silicon
                     } else {
-                        "https://dl.static-php.dev/static-php-cli/common/php-8.2.12-cli-macos-x86_64.tar.gz" // Intel Mac
+                        format!("https://dl.static-php.dev/static-php-cli/common/php-{}-cli-macos-x86_64.tar.gz", version) // Intel Mac
                     };
                     (mac_url, "php")
                 },               
@@ -31,10 +43,29 @@ pub fn get_package_info(package_name: &str, _version: &str) -> Option<PackageInf
 
             Some(PackageInfo {
                 name: package_name.to_string(),
-                url: url.to_string(),
+                url,
                 bin_name: bin_name.to_string(),
             })
         },
+        "php-fpm" => {
+            // static-php-cli FPM derlemesi; Windows için resmi bir php-fpm yok
+            let url = match os {
+                "macos" => {
+                    if arch == "aarch64" {
+                        "https://dl.static-php.dev/static-php-cli/common/php-8.2.12-fpm-macos-aarch64.tar.gz"
+                    } else {
+                        "https://dl.static-php.dev/static-php-cli/common/php-8.2.12-fpm-macos-x86_64.tar.gz"
+                    }
+                },
+                _ => return None,
+            };
+
+            Some(PackageInfo {
+                name: package_name.to_string(),
+                url: url.to_string(),
+                bin_name: "php-fpm".to_string(),
+            })
+        },
         "mysql" => {
             let (url, bin_name) = match os {
                 "windows" => (
@@ -58,6 +89,53 @@ pub fn get_package_info(package_name: &str, _version: &str) -> Option<PackageInf
                 bin_name: bin_name.to_string(),
             })
         },
+        "nginx" => {
+            let (url, bin_name) = match os {
+                "windows" => (
+                    "https://nginx.org/download/nginx-1.26.2.zip",
+                    "nginx.exe"
+                ),
+                "macos" => {
+                    let mac_url = if arch == "aarch64" {
+                        "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/nginx-1.26.2-macos-arm64.tar.gz"
+                    } else {
+                        "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/nginx-1.26.2-macos-x86_64.tar.gz"
+                    };
+                    (mac_url, "nginx")
+                },
+                _ => return None,
+            };
+
+            Some(PackageInfo {
+                name: package_name.to_string(),
+                url: url.to_string(),
+                bin_name: bin_name.to_string(),
+            })
+        },
+        "apache" => {
+            // Apache httpd; modüller ve mime.types ikilinin bir üst dizininde (ServerRoot) durur
+            let (url, bin_name) = match os {
+                "windows" => (
+                    "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/httpd-2.4.62-win64-VS17.zip",
+                    "httpd.exe"
+                ),
+                "macos" => {
+                    let mac_url = if arch == "aarch64" {
+                        "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/httpd-2.4.62-macos-arm64.tar.gz"
+                    } else {
+                        "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/httpd-2.4.62-macos-x86_64.tar.gz"
+                    };
+                    (mac_url, "httpd")
+                },
+                _ => return None,
+            };
+
+            Some(PackageInfo {
+                name: package_name.to_string(),
+                url: url.to_string(),
+                bin_name: bin_name.to_string(),
+            })
+        },
         "adminer" => {
             Some(PackageInfo {
                 name: package_name.to_string(),
@@ -67,4 +145,75 @@ pub fn get_package_info(package_name: &str, _version: &str) -> Option<PackageInf
         },
         _ => None,
     }
-}
\ No newline at end of file
+}
+
+/// Registry'deki eklenti ikililerinin derlendiği PHP sürümü (major.minor, NTS).
+pub const PHP_EXTENSION_VERSION: &str = "8.2";
+
+/// Registry'deki eklentiler bu PHP sürümüne yüklenebilir mi? (örn: "8.2.12" evet, "8.3.10" hayır)
+pub fn extensions_compatible(php_version: &str) -> bool {
+    php_version == "latest"
+        || php_version == PHP_EXTENSION_VERSION
+        || php_version.strip_prefix(PHP_EXTENSION_VERSION).is_some_and(|rest| rest.starts_with('.'))
+}
+
+/// PHP eklentisinin bu platform için derlenmiş ikilisi; Windows'ta DLL, diğerlerinde `.so` dosyası.
+pub fn get_php_extension_info(extension: &str) -> Option<PackageInfo> {
+    let extension = extension.to_lowercase();
+    if !PHP_EXTENSIONS.contains(&extension.as_str()) {
+        return None;
+    }
+
+    let platform = match (env::consts::OS, env::consts::ARCH) {
+        ("windows", _) => "win32-vs16-x64",
+        ("macos", "aarch64") => "macos-aarch64",
+        ("macos", _) => "macos-x86_64",
+        _ => return None,
+    };
+
+    // Eklentiler varsayılan PHP sürümüne (PHP_EXTENSION_VERSION) göre derlenir
+    let bin_name = if env::consts::OS == "windows" {
+        format!("php_{}.dll", extension)
+    } else {
+        format!("{}.so", extension)
+    };
+
+    Some(PackageInfo {
+        url: format!(
+            "https://github.com/0xffructose/fampp-core/releases/download/BinaryUpdate/php-{}-{}-{}-{}",
+            PHP_EXTENSION_VERSION, extension, platform, bin_name
+        ),
+        name: extension,
+        bin_name,
+    })
+}
+
+/// PHP'nin `packages/` altındaki dizini: varsayılan sürüm `php`, sitelere özel sürümler `php-<sürüm>`.
+pub fn php_package(version: Option<&str>) -> String {
+    match version {
+        Some(version) if version != "latest" => format!("php-{}", version),
+        _ => "php".to_string(),
+    }
+}
+
+/// Paketin `~/.fampp` altına kurulu olup olmadığını söyler; Adminer tek dosya olarak www/ içine kopyalanır.
+pub fn is_installed(base_path: &Path, package_name: &str) -> bool {
+    match package_name {
+        "adminer" => base_path.join("www").join("adminer.php").exists(),
+        _ => base_path.join("packages").join(package_name).is_dir(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extensions_compatible_matches_major_minor() {
+        assert!(extensions_compatible("latest"));
+        assert!(extensions_compatible("8.2"));
+        assert!(extensions_compatible("8.2.12"));
+        assert!(!extensions_compatible("8.3.10"));
+        assert!(!extensions_compatible("8.20.1"));
+    }
+}
@@ -0,0 +1,147 @@
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{render, warn_pinned_php, PhpFpmService, Service, ServiceContext};
+use crate::core::network;
+use crate::core::settings::{expand_home, AppSettings, FpmListen, FpmSettings, SiteSettings};
+
+const DEFAULT_PORT: u16 = 8081;
+
+// `.htaccess` kurallarının ve php-fpm aktarımının çalışması için gereken en küçük modül kümesi
+const MODULES: [&str; 10] = [
+    "authz_core", "dir", "mime", "rewrite", "alias", "autoindex", "log_config", "setenvif", "proxy", "proxy_fcgi",
+];
+
+/// Apache httpd. `.htaccess` ile çalışan projeler için her site `AllowOverride All` ile kendi sanal
+/// sunucusunda sunulur; PHP istekleri mod_proxy_fcgi ile php-fpm'e gider.
+pub struct ApacheService {
+    sites: BTreeMap<String, SiteSettings>,
+    tld: String,
+    fpm: FpmSettings,
+}
+
+impl ApacheService {
+    pub fn new(settings: &AppSettings) -> Self {
+        Self { sites: settings.sites.clone(), tld: settings.proxy.tld.clone(), fpm: settings.fpm.clone() }
+    }
+
+    pub fn config_path(base_path: &Path) -> PathBuf {
+        base_path.join("config").join("apache").join("httpd.conf")
+    }
+
+    fn runtime_dir(base_path: &Path) -> PathBuf {
+        base_path.join("data").join("apache")
+    }
+
+    // İkili dosya `<ServerRoot>/bin/httpd` altında durur; modules/ ve conf/ ServerRoot'a göredir
+    fn server_root(bin_path: &Path) -> &Path {
+        bin_path.parent().and_then(Path::parent).unwrap_or(bin_path)
+    }
+
+    fn modules() -> Vec<String> {
+        let mut modules = Vec::new();
+        // Windows'ta MPM ikiliye gömülüdür
+        if cfg!(unix) {
+            modules.push("LoadModule mpm_event_module modules/mod_mpm_event.so".to_string());
+            modules.push("LoadModule unixd_module modules/mod_unixd.so".to_string());
+        }
+        modules.extend(MODULES.iter().map(|name| format!("LoadModule {}_module modules/mod_{}.so", name, name)));
+        modules
+    }
+
+    // mod_proxy_fcgi adresi: soket için `proxy:unix:/yol|fcgi://localhost/`, TCP için `proxy:fcgi://host:port`
+    fn fastcgi_handler(&self, base_path: &Path) -> String {
+        match self.fpm.listen {
+            FpmListen::Socket => format!("proxy:unix:{}|fcgi://localhost/", PhpFpmService::socket_path(base_path).display()),
+            FpmListen::Tcp => format!("proxy:fcgi://{}", PhpFpmService::listen_address(base_path, &self.fpm)),
+        }
+    }
+
+    fn render_config(&self, ctx: &ServiceContext, bin_path: &Path, port: u16) -> String {
+        let vhost = |server_name: String, root: PathBuf, front_controller: Option<&String>| {
+            render(include_str!("../../../templates/apache/vhost.conf"), &[
+                ("port", port.to_string()),
+                ("server_name", server_name),
+                ("root", root.display().to_string()),
+                ("fallback", match front_controller {
+                    Some(front) => format!("        FallbackResource /{}", front.trim_start_matches('/')),
+                    None => String::new(),
+                }),
+            ])
+        };
+
+        // İlk sanal sunucu, eşleşmeyen isteklerin düştüğü varsayılandır
+        let mut vhosts = vec![vhost("localhost".to_string(), ctx.base_path.join("www"), None)];
+        for (name, site) in &self.sites {
+            let domain = site.domain(name, &self.tld);
+            vhosts.push(vhost(format!("{}\n    ServerAlias *.{}", domain, domain), expand_home(&site.root), site.front_controller.as_ref()));
+        }
+
+        let logs = ctx.base_path.join("logs");
+        let error_log = if cfg!(windows) {
+            logs.join("apache-error.log").display().to_string()
+        } else {
+            "/dev/stderr".to_string()
+        };
+
+        render(include_str!("../../../templates/apache/httpd.conf"), &[
+            ("server_root", Self::server_root(bin_path).display().to_string()),
+            ("modules", Self::modules().join("\n")),
+            ("listen", network::socket_addr(ctx.bind, port)),
+            ("pid", Self::runtime_dir(ctx.base_path).join("httpd.pid").display().to_string()),
+            ("error_log", error_log),
+            ("access_log", logs.join("apache-access.log").display().to_string()),
+            ("fastcgi", self.fastcgi_handler(ctx.base_path)),
+            ("vhosts", vhosts.join("\n")),
+        ])
+    }
+}
+
+impl Service for ApacheService {
+    fn name(&self) -> &str {
+        "apache"
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        settings.service(self.name()).port.or(Some(DEFAULT_PORT))
+    }
+
+    fn data_dirs(&self, base_path: &Path) -> Vec<PathBuf> {
+        vec![Self::runtime_dir(base_path)]
+    }
+
+    // Her başlatmada ve `fampp reload apache` öncesinde config.toml'dan yeniden üretilir
+    fn init(&self, ctx: &ServiceContext, bin_path: &Path) -> Result<(), String> {
+        warn_pinned_php(ctx.i18n, "apache", &self.sites);
+
+        let path = Self::config_path(ctx.base_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, self.render_config(ctx, bin_path, ctx.port.unwrap_or(DEFAULT_PORT))).map_err(|e| e.to_string())
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, _port: Option<u16>) -> Vec<String> {
+        vec![
+            "-f".to_string(),
+            Self::config_path(ctx.base_path).to_string_lossy().to_string(),
+            "-DFOREGROUND".to_string(),
+        ]
+    }
+
+    // SIGWINCH: graceful-stop, işlenen istekler tamamlanır
+    fn shutdown_signal(&self) -> &str {
+        "WINCH"
+    }
+
+    // SIGUSR1: graceful restart, yeni yapılandırma bağlantıları kesmeden okunur
+    fn reload_signal(&self) -> Option<&str> {
+        Some("USR1")
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        println!("   {} http://127.0.0.1:{}", "🌐 Localhost :".cyan().bold(), port.unwrap_or_default());
+    }
+}
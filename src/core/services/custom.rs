@@ -0,0 +1,125 @@
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::{Service, ServiceContext};
+use crate::core::settings::{AppSettings, Probe};
+
+/// `config.toml` içinde `command` ile tanımlanan ya da Procfile'dan içe aktarılan kullanıcı servisi
+/// (örn: `php artisan queue:work`, Vite dev sunucusu).
+pub struct CustomService {
+    name: String,
+    command: String,
+}
+
+impl CustomService {
+    pub fn new(name: &str, command: &str) -> Self {
+        Self { name: name.to_string(), command: command.to_string() }
+    }
+}
+
+impl Service for CustomService {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    // Komut kabuk üzerinden çalışır; böylece pipe, `$PORT` gibi değişkenler de kullanılabilir
+    fn binary(&self, _base_path: &Path) -> Option<PathBuf> {
+        #[cfg(unix)]
+        return Some(PathBuf::from("sh"));
+
+        #[cfg(windows)]
+        return Some(PathBuf::from("cmd"));
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        settings.service(&self.name).port
+    }
+
+    fn args(&self, _ctx: &ServiceContext, _bin_path: &Path, _port: Option<u16>) -> Vec<String> {
+        // Servis adı $0 olur; config'teki ek argümanlara komut içinden "$@" ile ulaşılır.
+        // Kabuğun alt süreçleri süreç grubuyla birlikte durdurulduğu için `exec` gerekmez.
+        #[cfg(unix)]
+        return vec!["-c".to_string(), self.command.clone(), self.name.clone()];
+
+        #[cfg(windows)]
+        return vec!["/C".to_string(), self.command.clone()];
+    }
+
+    fn env(&self, ctx: &ServiceContext, port: Option<u16>) -> BTreeMap<String, String> {
+        let mut env = BTreeMap::new();
+
+        // FAMPP'ın kurduğu ikililer (php, mysql...) PATH'te önce gelsin ki `php artisan` onları kullansın
+        let mut paths: Vec<PathBuf> = super::installed_binaries(ctx.base_path)
+            .iter()
+            .filter_map(|bin| bin.parent().map(Path::to_path_buf))
+            .collect();
+        if let Some(system_path) = std::env::var_os("PATH") {
+            paths.extend(std::env::split_paths(&system_path));
+        }
+        if let Ok(path) = std::env::join_paths(paths) {
+            env.insert("PATH".to_string(), path.to_string_lossy().to_string());
+        }
+
+        if let Some(port) = port {
+            env.insert("PORT".to_string(), port.to_string());
+        }
+        env.insert("HOST".to_string(), ctx.bind.to_string());
+
+        env
+    }
+
+    fn readiness(&self, settings: &AppSettings) -> Probe {
+        let svc_settings = settings.service(&self.name);
+        match (svc_settings.readiness, svc_settings.port) {
+            // Portu olmayan bir worker'da TCP yoklaması anlamsız
+            (Probe::Tcp { port: None }, None) => Probe::None,
+            (probe, _) => probe,
+        }
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        println!("   {} {}", "⚙️  Command :".magenta().bold(), self.command);
+        if let Some(port) = port {
+            println!("   {} http://127.0.0.1:{}", "🌐 Localhost :".cyan().bold(), port);
+        }
+    }
+}
+
+/// Procfile satırlarını (`web: php -S ...`) servis adı ve komut çiftlerine ayırır.
+pub fn parse_procfile(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (name, command) = line.split_once(':')?;
+            let name = name.trim();
+            let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            valid.then(|| (name.to_string(), command.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_procfile_reads_name_and_command() {
+        let content = "web: php -S 0.0.0.0:$PORT -t public\nqueue:php artisan queue:work --tries=3\n";
+        assert_eq!(
+            parse_procfile(content),
+            vec![
+                ("web".to_string(), "php -S 0.0.0.0:$PORT -t public".to_string()),
+                ("queue".to_string(), "php artisan queue:work --tries=3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_procfile_skips_comments_blank_and_invalid_lines() {
+        let content = "# dev processes\n\n  \nno colon here\nbad name: echo hi\n: echo nameless\nworker_1: sleep 1\n";
+        assert_eq!(parse_procfile(content), vec![("worker_1".to_string(), "sleep 1".to_string())]);
+    }
+}
@@ -0,0 +1,61 @@
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use super::{Service, ServiceContext};
+use crate::core::settings::{AppSettings, Probe};
+
+/// `*.test` sorgularını yanıtlayan yerel DNS sunucusu; FAMPP kendini gizli `serve dns` komutuyla başlatır.
+pub struct DnsService {
+    tld: String,
+}
+
+impl DnsService {
+    pub fn new(settings: &AppSettings) -> Self {
+        Self { tld: settings.proxy.tld.trim_matches('.').to_string() }
+    }
+}
+
+impl Service for DnsService {
+    fn name(&self) -> &str {
+        "dns"
+    }
+
+    fn binary(&self, _base_path: &Path) -> Option<PathBuf> {
+        std::env::current_exe().ok()
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        Some(settings.dns.port)
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, port: Option<u16>) -> Vec<String> {
+        let port = port.expect("DNS her zaman bir porta sahiptir");
+        vec![
+            "serve".to_string(),
+            "dns".to_string(),
+            "--bind".to_string(),
+            ctx.bind.to_string(),
+            "--port".to_string(),
+            port.to_string(),
+        ]
+    }
+
+    // UDP portu TCP ile yoklanamaz; süreç ayakta olduğu sürece hazır sayılır
+    fn readiness(&self, settings: &AppSettings) -> Probe {
+        match settings.service(self.name()).readiness {
+            Probe::Tcp { port: None } => Probe::None,
+            configured => configured,
+        }
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        let port = port.unwrap_or_default();
+        println!("   {} 127.0.0.1:{} (udp) *.{}", "🧭 DNS :".cyan().bold(), port, self.tld);
+        if cfg!(target_os = "macos") {
+            println!(
+                "   {} sudo mkdir -p /etc/resolver && printf 'nameserver 127.0.0.1\\nport {}\\n' | sudo tee /etc/resolver/{}",
+                "💡".yellow(), port, self.tld
+            );
+        }
+    }
+}
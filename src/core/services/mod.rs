@@ -0,0 +1,204 @@
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::locale::I18n;
+use crate::core::registry::{get_package_info, PACKAGES, PHP_VERSION};
+use crate::core::settings::{AppSettings, Probe, SiteSettings};
+
+mod apache;
+mod custom;
+mod dns;
+mod mysql;
+mod nginx;
+mod php;
+mod php_fpm;
+mod proxy;
+
+pub use apache::ApacheService;
+pub use custom::{parse_procfile, CustomService};
+pub use dns::DnsService;
+pub use mysql::MysqlService;
+pub use nginx::NginxService;
+pub use php::PhpService;
+pub use php_fpm::PhpFpmService;
+pub use proxy::ProxyService;
+
+/// Bir servisin başlatılırken ihtiyaç duyduğu ortak bağlam.
+pub struct ServiceContext<'a> {
+    pub base_path: &'a Path,
+    // `fampp up` ile ön planda mı çalışıyor? (loglar dosya yerine terminale akar)
+    pub attached: bool,
+    // Servisin dinleyeceği adres (örn: 127.0.0.1 ya da `--lan` ile 0.0.0.0)
+    pub bind: &'a str,
+    // Boş port aranıp bulunduktan sonra servise ayrılan port; yapılandırma dosyaları bununla üretilir
+    pub port: Option<u16>,
+    // Hazırlık sırasında kullanıcıya gösterilen uyarılar için
+    pub i18n: &'a I18n,
+}
+
+/// FAMPP'ın yönettiği her servisin (php, mysql...) tarifi.
+/// `start`, `stop`, `status` ve `logs` yalnızca bu arayüz üzerinden çalışır.
+pub trait Service {
+    fn name(&self) -> &str;
+
+    /// İkili dosyanın arandığı registry paketi.
+    fn package(&self) -> &str {
+        self.name()
+    }
+
+    fn binary(&self, base_path: &Path) -> Option<PathBuf> {
+        let info = get_package_info(self.package(), "latest")?;
+        find_executable(&base_path.join("packages").join(self.package()), &info.bin_name)
+    }
+
+    /// Servisin dinlediği varsayılan port; portu olmayan worker'lar için `None`.
+    fn default_port(&self, settings: &AppSettings) -> Option<u16>;
+
+    /// Varsayılan port doluysa bir sonraki boş portu mu arasın, yoksa olduğu gibi mi denesin?
+    fn probe_free_port(&self) -> bool {
+        false
+    }
+
+    fn data_dirs(&self, _base_path: &Path) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// İlk çalıştırmadan önce yapılması gereken hazırlık (örn: MySQL sistem tabloları).
+    fn init(&self, _ctx: &ServiceContext, _bin_path: &Path) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn args(&self, ctx: &ServiceContext, bin_path: &Path, port: Option<u16>) -> Vec<String>;
+
+    /// Servisin kendi eklediği ortam değişkenleri (config.toml'dakiler bunları ezer).
+    fn env(&self, _ctx: &ServiceContext, _port: Option<u16>) -> BTreeMap<String, String> {
+        BTreeMap::new()
+    }
+
+    fn readiness(&self, settings: &AppSettings) -> Probe {
+        settings.service(self.name()).readiness
+    }
+
+    /// Nazik kapatma için gönderilen sinyal (süre dolarsa SIGKILL gelir).
+    fn shutdown_signal(&self) -> &str {
+        "TERM"
+    }
+
+    /// Kesintisiz yapılandırma yenilemesi için sinyal (örn: nginx için HUP, php-fpm için USR2).
+    fn reload_signal(&self) -> Option<&str> {
+        None
+    }
+
+    /// Servisin gerçekte dinlediği portu kendi çıktısından okur; bilinmiyorsa `None`.
+    fn bound_port(&self, _output: &str) -> Option<u16> {
+        None
+    }
+
+    /// Servis başladıktan sonra kullanıcıya gösterilen bağlantı bilgileri.
+    fn print_endpoints(&self, port: Option<u16>);
+}
+
+/// FAMPP'ın paketlediği yerleşik servisler; php-fpm, nginx ve apache yalnızca registry'de bu platform için
+/// derlemeleri varsa listelenir.
+pub fn builtin(settings: &AppSettings) -> Vec<Box<dyn Service>> {
+    let mut services: Vec<Box<dyn Service>> = vec![Box::new(PhpService::new())];
+    if get_package_info("php-fpm", "latest").is_some() {
+        services.push(Box::new(PhpFpmService::new(settings)));
+    }
+    services.push(Box::new(MysqlService));
+    if get_package_info("nginx", "latest").is_some() {
+        services.push(Box::new(NginxService::new(settings)));
+    }
+    if get_package_info("apache", "latest").is_some() {
+        services.push(Box::new(ApacheService::new(settings)));
+    }
+    services
+}
+
+/// Yerleşik servisler, `[sites]` altındaki PHP siteleri, yönlendirilecek alan adı varsa reverse proxy ile DNS
+/// ve `command` ile tanımlanan kullanıcı servisleri.
+pub fn all(settings: &AppSettings) -> Vec<Box<dyn Service>> {
+    let mut services = builtin(settings);
+
+    for (name, site) in &settings.sites {
+        services.push(Box::new(PhpService::site(name, site)));
+    }
+
+    if !settings.sites.is_empty() || !settings.proxy.routes.is_empty() {
+        services.push(Box::new(ProxyService::new(settings)));
+        services.push(Box::new(DnsService::new(settings)));
+    }
+
+    let mut custom: Vec<(&String, &String)> = settings.services
+        .iter()
+        .filter_map(|(name, svc)| svc.command.as_ref().map(|cmd| (name, cmd)))
+        .filter(|(name, _)| !services.iter().any(|b| b.name() == name.as_str()))
+        .collect();
+    custom.sort();
+
+    for (name, command) in custom {
+        services.push(Box::new(CustomService::new(name, command)));
+    }
+
+    services
+}
+
+pub fn get(name: &str, settings: &AppSettings) -> Option<Box<dyn Service>> {
+    all(settings).into_iter().find(|svc| svc.name() == name)
+}
+
+/// Sitenin `php-<ad>` servisi yerleşik bir paketle (örn: php-fpm) aynı adı taşıyor mu? Öyleyse `get` her zaman
+/// paketi bulur ve site kendi adıyla başlatılamaz. Kullanıcı servisleri `php-` önekini zaten alamaz.
+pub fn site_name_taken(site: &str) -> bool {
+    PACKAGES.contains(&PhpService::instance_name(site).as_str())
+}
+
+/// Servisin nazik kapatma sinyali; kayıtlı olmayan süreçler (örn: süpervizör) için SIGTERM.
+pub fn shutdown_signal(name: &str, settings: &AppSettings) -> String {
+    get(name, settings).map(|svc| svc.shutdown_signal().to_string()).unwrap_or_else(|| "TERM".to_string())
+}
+
+// Registry paketlerinden kurulu olanların ikili dosyaları (php, mysql...)
+fn installed_binaries(base_path: &Path) -> Vec<PathBuf> {
+    PACKAGES
+        .iter()
+        .filter_map(|package| {
+            let info = get_package_info(package, "latest")?;
+            find_executable(&base_path.join("packages").join(package), &info.bin_name)
+        })
+        .collect()
+}
+
+// nginx ve apache bütün siteleri tek php-fpm havuzuna gönderir; sitede sabitlenmiş farklı bir PHP sürümü
+// orada geçerli olmaz, yalnızca sitenin kendi `php-<ad>` sunucusunda kullanılır
+fn warn_pinned_php(i18n: &I18n, server: &str, sites: &BTreeMap<String, SiteSettings>) {
+    for (name, site) in sites {
+        if let Some(version) = site.php.as_deref().filter(|version| *version != PHP_VERSION) {
+            println!("{} {}: {} {} {} ≠ {}", "⚠️".yellow(), server, name.bold(), i18n.t("pinned_php_shared_fpm"), PHP_VERSION, version.yellow());
+            println!("   💡 {} {}", i18n.t("pinned_php_tip"), format!("'fampp start {}'", PhpService::instance_name(name)).yellow());
+        }
+    }
+}
+
+/// `templates/` altındaki yapılandırma şablonlarında `{{ad}}` yer tutucularını doldurur.
+fn render(template: &str, vars: &[(&str, String)]) -> String {
+    vars.iter().fold(template.to_string(), |text, (key, value)| text.replace(&format!("{{{{{}}}}}", key), value))
+}
+
+pub fn find_executable(dir: &Path, bin_name: &str) -> Option<PathBuf> {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_file() && path.file_name().and_then(|n| n.to_str()) == Some(bin_name) {
+                return Some(path);
+            } else if path.is_dir()
+                && let Some(found) = find_executable(&path, bin_name)
+            {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
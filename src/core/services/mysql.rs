@@ -0,0 +1,84 @@
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::{Service, ServiceContext};
+use crate::core::settings::AppSettings;
+
+/// Taşınabilir MySQL sunucusu; veriler `~/.fampp/data/mysql` altında tutulur.
+pub struct MysqlService;
+
+impl MysqlService {
+    fn data_dir(base_path: &Path) -> PathBuf {
+        base_path.join("data").join("mysql")
+    }
+
+    // İkili dosya `<basedir>/bin/mysqld` altında durur
+    fn basedir(bin_path: &Path) -> &Path {
+        bin_path.parent().unwrap().parent().unwrap()
+    }
+}
+
+impl Service for MysqlService {
+    fn name(&self) -> &str {
+        "mysql"
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        Some(settings.ports.mysql)
+    }
+
+    fn data_dirs(&self, base_path: &Path) -> Vec<PathBuf> {
+        vec![Self::data_dir(base_path)]
+    }
+
+    fn init(&self, ctx: &ServiceContext, bin_path: &Path) -> Result<(), String> {
+        let db_data_dir = Self::data_dir(ctx.base_path);
+
+        let is_empty = fs::read_dir(&db_data_dir).map_err(|e| e.to_string())?.next().is_none();
+        if !is_empty {
+            return Ok(());
+        }
+
+        println!("⏳ MySQL ilk kez hazırlanıyor (Sistem tabloları oluşturuluyor)...");
+        let output = Command::new(bin_path)
+            .arg("--initialize-insecure")
+            .arg(format!("--basedir={}", Self::basedir(bin_path).to_str().unwrap()))
+            .arg(format!("--datadir={}", db_data_dir.to_str().unwrap()))
+            .output()
+            .map_err(|e| format!("MySQL ilklendirilemedi: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("İlklendirme Hatası: {}", String::from_utf8_lossy(&output.stderr)));
+        }
+
+        println!("✅ MySQL veritabanı dosyaları başarıyla oluşturuldu.");
+        Ok(())
+    }
+
+    fn args(&self, ctx: &ServiceContext, bin_path: &Path, port: Option<u16>) -> Vec<String> {
+        let port = port.expect("MySQL her zaman bir porta sahiptir");
+        let mut args = vec![
+            format!("--basedir={}", Self::basedir(bin_path).to_str().unwrap()),
+            format!("--datadir={}", Self::data_dir(ctx.base_path).to_str().unwrap()),
+            format!("--port={}", port),
+            // MySQL varsayılan olarak tüm arayüzleri dinler; açıkça belirtmedikçe yalnızca bu makine
+            format!("--bind-address={}", ctx.bind),
+        ];
+
+        // Ön planda (`fampp up`) hatalar stderr'e aksın ki terminalde görünsün
+        if !ctx.attached {
+            let log_file = ctx.base_path.join("logs").join("mysql.log");
+            args.push(format!("--log-error={}", log_file.to_str().unwrap())); // Hataları dosyaya yazdır!
+        }
+
+        args
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        println!("   {} 127.0.0.1:{}", "🗄️  Host :".blue().bold(), port.unwrap_or_default());
+        println!("   {} root", "👤 User :".blue().bold());
+        println!("   {} (None)", "🔑 Pass :".blue().bold());
+    }
+}
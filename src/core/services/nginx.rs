@@ -0,0 +1,118 @@
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{render, warn_pinned_php, PhpFpmService, Service, ServiceContext};
+use crate::core::network;
+use crate::core::settings::{expand_home, AppSettings, FpmSettings, SiteSettings};
+
+const DEFAULT_PORT: u16 = 8080;
+
+/// nginx web sunucusu. `~/.fampp/www` varsayılan sunucu, her site ise kendi alan adıyla ayrı bir `server`
+/// bloğu olarak sunulur; PHP istekleri FastCGI ile php-fpm'e gider.
+pub struct NginxService {
+    sites: BTreeMap<String, SiteSettings>,
+    tld: String,
+    fpm: FpmSettings,
+}
+
+impl NginxService {
+    pub fn new(settings: &AppSettings) -> Self {
+        Self { sites: settings.sites.clone(), tld: settings.proxy.tld.clone(), fpm: settings.fpm.clone() }
+    }
+
+    pub fn config_path(base_path: &Path) -> PathBuf {
+        base_path.join("config").join("nginx").join("nginx.conf")
+    }
+
+    // PID ve geçici dosyalar (istek gövdeleri, FastCGI tamponları)
+    fn runtime_dir(base_path: &Path) -> PathBuf {
+        base_path.join("data").join("nginx")
+    }
+
+    fn render_config(&self, ctx: &ServiceContext, port: u16) -> String {
+        let fastcgi = PhpFpmService::listen_address(ctx.base_path, &self.fpm);
+        let listen = network::socket_addr(ctx.bind, port);
+
+        let server = |server_name: String, root: PathBuf, front_controller: Option<&String>, default: bool| {
+            render(include_str!("../../../templates/nginx/site.conf"), &[
+                ("listen", listen.clone()),
+                ("default_server", if default { " default_server".to_string() } else { String::new() }),
+                ("server_name", server_name),
+                ("root", root.display().to_string()),
+                // Front controller tanımlıysa dosya olarak bulunamayan her istek ona gider
+                ("fallback", match front_controller {
+                    Some(front) => format!("/{}?$query_string", front.trim_start_matches('/')),
+                    None => "=404".to_string(),
+                }),
+                ("fastcgi", fastcgi.clone()),
+            ])
+        };
+
+        let mut servers = vec![server("localhost".to_string(), ctx.base_path.join("www"), None, true)];
+        for (name, site) in &self.sites {
+            let domain = site.domain(name, &self.tld);
+            servers.push(server(format!("{} *.{}", domain, domain), expand_home(&site.root), site.front_controller.as_ref(), false));
+        }
+
+        let runtime = Self::runtime_dir(ctx.base_path);
+        render(include_str!("../../../templates/nginx/nginx.conf"), &[
+            // Ön planda da arka planda da hatalar servisin kendi loguna (`fampp logs nginx`) akar
+            ("error_log", "stderr".to_string()),
+            ("pid", runtime.join("nginx.pid").display().to_string()),
+            ("access_log", ctx.base_path.join("logs").join("nginx-access.log").display().to_string()),
+            ("temp", runtime.display().to_string()),
+            ("servers", servers.join("\n")),
+        ])
+    }
+}
+
+impl Service for NginxService {
+    fn name(&self) -> &str {
+        "nginx"
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        settings.service(self.name()).port.or(Some(DEFAULT_PORT))
+    }
+
+    fn data_dirs(&self, base_path: &Path) -> Vec<PathBuf> {
+        vec![Self::runtime_dir(base_path)]
+    }
+
+    // Her başlatmada ve `fampp reload nginx` öncesinde config.toml'dan yeniden üretilir
+    fn init(&self, ctx: &ServiceContext, _bin_path: &Path) -> Result<(), String> {
+        warn_pinned_php(ctx.i18n, "nginx", &self.sites);
+
+        let path = Self::config_path(ctx.base_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, self.render_config(ctx, ctx.port.unwrap_or(DEFAULT_PORT))).map_err(|e| e.to_string())
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, _port: Option<u16>) -> Vec<String> {
+        vec![
+            "-p".to_string(),
+            Self::runtime_dir(ctx.base_path).to_string_lossy().to_string(),
+            "-c".to_string(),
+            Self::config_path(ctx.base_path).to_string_lossy().to_string(),
+            "-e".to_string(),
+            "stderr".to_string(),
+        ]
+    }
+
+    // SIGQUIT açık bağlantıların bitmesini bekler
+    fn shutdown_signal(&self) -> &str {
+        "QUIT"
+    }
+
+    fn reload_signal(&self) -> Option<&str> {
+        Some("HUP")
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        println!("   {} http://127.0.0.1:{}", "🌐 Localhost :".cyan().bold(), port.unwrap_or_default());
+    }
+}
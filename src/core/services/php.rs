@@ -0,0 +1,194 @@
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{find_executable, Service, ServiceContext};
+use crate::core::network;
+use crate::core::phpext;
+use crate::core::phpini;
+use crate::core::registry;
+use crate::core::settings::{expand_home, AppSettings, SiteSettings};
+
+/// PHP'nin yerleşik geliştirme sunucusu (`php -S`). Varsayılan örnek `~/.fampp/www` dizinini,
+/// `[sites.<ad>]` ile tanımlanan her site ise kendi kök dizinini `php-<ad>` adıyla ayrı bir portta sunar.
+pub struct PhpService {
+    name: String,
+    // Sitede `php` sürümü verilmişse `php-<sürüm>`, değilse varsayılan `php` paketi
+    package: String,
+    site: Option<SiteSettings>,
+}
+
+impl PhpService {
+    pub fn new() -> Self {
+        Self { name: "php".to_string(), package: registry::php_package(None), site: None }
+    }
+
+    pub fn site(name: &str, site: &SiteSettings) -> Self {
+        Self {
+            name: Self::instance_name(name),
+            package: registry::php_package(site.php.as_deref()),
+            site: Some(site.clone()),
+        }
+    }
+
+    /// Sitenin servis adı; PID, port ve log dosyaları bu adla tutulur (örn: `php-shop`).
+    pub fn instance_name(site: &str) -> String {
+        format!("php-{}", site)
+    }
+
+    // Site, registry eklentilerinin derlendiğinden farklı bir PHP sürümüne sabitlenmişse o sürüm
+    fn incompatible_version(&self) -> Option<&str> {
+        self.site.as_ref()?.php.as_deref().filter(|version| !registry::extensions_compatible(version))
+    }
+
+    // Örneğin `-c` ile kullandığı php.ini; eklentileri yükleyemeyen sürümler ortak dosyanın eklentisiz kopyasını alır
+    fn ini_path(&self, base_path: &Path) -> PathBuf {
+        match self.incompatible_version() {
+            Some(version) => phpini::versioned_path(base_path, version),
+            None => phpini::path(base_path),
+        }
+    }
+
+    // FAMPP'ın gömülü router betiğinin diske yazıldığı yer
+    fn managed_router(base_path: &Path) -> PathBuf {
+        base_path.join("config").join("php").join("router.php")
+    }
+
+    // Sitenin kullanacağı router: kendi betiği, yoksa front controller tanımlıysa FAMPP'ınki
+    fn router(&self, base_path: &Path) -> Option<PathBuf> {
+        let site = self.site.as_ref()?;
+        match (&site.router, &site.front_controller) {
+            (Some(router), _) => Some(expand_home(router)),
+            (None, Some(_)) => Some(Self::managed_router(base_path)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Service for PhpService {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn package(&self) -> &str {
+        &self.package
+    }
+
+    fn binary(&self, base_path: &Path) -> Option<PathBuf> {
+        let version = self.site.as_ref().and_then(|site| site.php.as_deref()).unwrap_or("latest");
+        let info = registry::get_package_info("php", version)?;
+        find_executable(&base_path.join("packages").join(&self.package), &info.bin_name)
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        self.site.as_ref().and_then(|site| site.port).or(Some(settings.ports.php))
+    }
+
+    fn probe_free_port(&self) -> bool {
+        true
+    }
+
+    fn init(&self, ctx: &ServiceContext, _bin_path: &Path) -> Result<(), String> {
+        let ini = phpini::ensure(ctx.base_path).map_err(|e| format!("php.ini: {}", e))?;
+
+        // Ortak php.ini her başlatmada yeniden kopyalanır ki `php ini set` değişiklikleri bu sürüme de yansısın
+        if let Some(version) = self.incompatible_version() {
+            let content = fs::read_to_string(&ini).map_err(|e| format!("php.ini: {}", e))?;
+            let (content, skipped) = phpext::without_downloaded(ctx.base_path, &content);
+            fs::write(self.ini_path(ctx.base_path), content).map_err(|e| format!("php.ini: {}", e))?;
+
+            if !skipped.is_empty() {
+                println!("{} {}: {} {}: {}", "⚠️".yellow(), self.name, ctx.i18n.t("ext_version_skipped"), version, skipped.join(", ").yellow());
+            }
+        }
+
+        // Gömülü router her başlatmada yeniden yazılır ki FAMPP güncellemeleri siteye yansısın
+        if self.site.as_ref().is_some_and(|site| site.router.is_none() && site.front_controller.is_some()) {
+            let path = Self::managed_router(ctx.base_path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            fs::write(&path, include_str!("../../../templates/router.php")).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, port: Option<u16>) -> Vec<String> {
+        let docroot = match &self.site {
+            Some(site) => expand_home(&site.root),
+            None => ctx.base_path.join("www"),
+        };
+        let port = port.expect("PHP her zaman bir porta sahiptir");
+
+        let mut args = vec![
+            "-c".to_string(),
+            self.ini_path(ctx.base_path).to_string_lossy().to_string(),
+        ];
+
+        if let Some(site) = &self.site {
+            for (key, value) in &site.ini {
+                args.push("-d".to_string());
+                args.push(format!("{}={}", key, value));
+            }
+        }
+
+        args.extend([
+            "-S".to_string(),
+            network::socket_addr(ctx.bind, port),
+            "-t".to_string(),
+            docroot.to_str().unwrap().to_string(),
+        ]);
+
+        // Router betiği verilirse her istek önce ondan geçer
+        if let Some(router) = self.router(ctx.base_path) {
+            args.push(router.to_string_lossy().to_string());
+        }
+
+        args
+    }
+
+    fn env(&self, _ctx: &ServiceContext, _port: Option<u16>) -> BTreeMap<String, String> {
+        let mut env = BTreeMap::new();
+        if let Some(front) = self.site.as_ref().and_then(|site| site.front_controller.as_ref()) {
+            env.insert("FAMPP_FRONT_CONTROLLER".to_string(), front.clone());
+        }
+        env
+    }
+
+    // "[...] PHP 8.2.12 Development Server (http://127.0.0.1:8001) started"
+    fn bound_port(&self, output: &str) -> Option<u16> {
+        output
+            .lines()
+            .rev()
+            .filter(|line| line.ends_with("started"))
+            .find_map(|line| line.split_once("Development Server (")?.1.split_once(')'))
+            .and_then(|(url, _)| url.rsplit(':').next()?.parse().ok())
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        println!("   {} http://127.0.0.1:{}", "🌐 Localhost :".cyan().bold(), port.unwrap_or_default());
+        if let Some(site) = &self.site {
+            println!("   {} {}", "📁 Root :".cyan().bold(), expand_home(&site.root).display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bound_port_reads_the_last_started_line() {
+        let output = "[Mon Oct 19 10:00:00 2026] PHP 8.2.12 Development Server (http://127.0.0.1:8000) started\n\
+                      [Mon Oct 19 10:05:00 2026] PHP 8.2.12 Development Server (http://127.0.0.1:8001) started\n";
+        assert_eq!(PhpService::new().bound_port(output), Some(8001));
+    }
+
+    #[test]
+    fn bound_port_handles_ipv6_and_ignores_failures() {
+        let service = PhpService::new();
+        assert_eq!(service.bound_port("PHP 8.3.10 Development Server (http://[::1]:8080) started"), Some(8080));
+        assert_eq!(service.bound_port("Failed to listen on 127.0.0.1:8000 (reason: Address already in use)"), None);
+    }
+}
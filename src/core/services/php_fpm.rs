@@ -0,0 +1,152 @@
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{Service, ServiceContext};
+use crate::core::network;
+use crate::core::phpini;
+use crate::core::settings::{AppSettings, FpmListen, FpmSettings, Probe};
+
+/// PHP FastCGI süreç yöneticisi. Yerleşik sunucunun aksine istekleri paralel worker'larla işler;
+/// önüne FAMPP'ın yönettiği bir web sunucusu ya da reverse proxy konur.
+pub struct PhpFpmService {
+    fpm: FpmSettings,
+    // Havuzun dinlediği adres; yapılandırma da uç nokta çıktısı da aynı ayarlardan üretilir
+    listen: String,
+}
+
+impl PhpFpmService {
+    pub fn new(settings: &AppSettings) -> Self {
+        Self {
+            fpm: settings.fpm.clone(),
+            listen: Self::listen_address(&settings.base_path, &settings.fpm),
+        }
+    }
+
+    /// Her başlatmada `[fpm]` ayarlarından yeniden üretilen yapılandırma dosyası.
+    pub fn config_path(base_path: &Path) -> PathBuf {
+        base_path.join("config").join("php-fpm").join("php-fpm.conf")
+    }
+
+    pub fn socket_path(base_path: &Path) -> PathBuf {
+        base_path.join("data").join("php-fpm.sock")
+    }
+
+    /// Web sunucusunun FastCGI isteklerini göndereceği adres (örn: `unix:/.../php-fpm.sock` ya da `127.0.0.1:9000`).
+    pub fn listen_address(base_path: &Path, fpm: &FpmSettings) -> String {
+        match fpm.listen {
+            FpmListen::Socket => format!("unix:{}", Self::socket_path(base_path).display()),
+            FpmListen::Tcp => network::socket_addr(network::LOOPBACK, fpm.port),
+        }
+    }
+
+    fn render_config(ctx: &ServiceContext, fpm: &FpmSettings) -> String {
+        let listen = match fpm.listen {
+            FpmListen::Socket => Self::socket_path(ctx.base_path).display().to_string(),
+            FpmListen::Tcp => network::socket_addr(ctx.bind, fpm.port),
+        };
+
+        // Ön planda (`fampp up`) hatalar terminale, arka planda servisin log dosyasına akar
+        let error_log = if ctx.attached {
+            "/dev/stderr".to_string()
+        } else {
+            ctx.base_path.join("logs").join("php-fpm.log").display().to_string()
+        };
+
+        let mut lines = vec![
+            "; FAMPP tarafından üretildi; her başlatmada config.toml'daki [fpm] bölümünden yeniden yazılır.".to_string(),
+            "[global]".to_string(),
+            format!("error_log = {}", error_log),
+            "daemonize = no".to_string(),
+            String::new(),
+            "[www]".to_string(),
+            format!("listen = {}", listen),
+            "listen.mode = 0660".to_string(),
+        ];
+
+        if let Some(user) = &fpm.user {
+            lines.push(format!("user = {}", user));
+        }
+        if let Some(group) = &fpm.group {
+            lines.push(format!("group = {}", group));
+        }
+
+        lines.extend([
+            "pm = dynamic".to_string(),
+            format!("pm.max_children = {}", fpm.max_children),
+            "pm.start_servers = 1".to_string(),
+            "pm.min_spare_servers = 1".to_string(),
+            format!("pm.max_spare_servers = {}", fpm.max_children.clamp(1, 3)),
+            // Worker çıktıları ve config.toml'daki ortam değişkenleri PHP'ye ulaşsın
+            "catch_workers_output = yes".to_string(),
+            "decorate_workers_output = no".to_string(),
+            "clear_env = no".to_string(),
+        ]);
+
+        for (key, value) in &fpm.pool {
+            lines.push(format!("{} = {}", key, value));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+impl Service for PhpFpmService {
+    fn name(&self) -> &str {
+        "php-fpm"
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        match settings.fpm.listen {
+            FpmListen::Socket => None,
+            FpmListen::Tcp => Some(settings.fpm.port),
+        }
+    }
+
+    fn init(&self, ctx: &ServiceContext, _bin_path: &Path) -> Result<(), String> {
+        phpini::ensure(ctx.base_path).map_err(|e| format!("php.ini: {}", e))?;
+
+        let path = Self::config_path(ctx.base_path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, Self::render_config(ctx, &self.fpm)).map_err(|e| e.to_string())?;
+
+        // Önceki çalıştırmadan kalan soket php-fpm'in açılmasını engeller
+        let _ = fs::remove_file(Self::socket_path(ctx.base_path));
+        Ok(())
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, _port: Option<u16>) -> Vec<String> {
+        vec![
+            "--nodaemonize".to_string(),
+            "--fpm-config".to_string(),
+            Self::config_path(ctx.base_path).to_string_lossy().to_string(),
+            "-c".to_string(),
+            phpini::path(ctx.base_path).to_string_lossy().to_string(),
+        ]
+    }
+
+    fn readiness(&self, settings: &AppSettings) -> Probe {
+        let configured = settings.service(self.name()).readiness;
+        // Unix soketi TCP ile yoklanamaz; süreç ayakta olduğu sürece hazır sayılır
+        match (settings.fpm.listen, &configured) {
+            (FpmListen::Socket, Probe::Tcp { port: None }) => Probe::None,
+            _ => configured,
+        }
+    }
+
+    // SIGQUIT, worker'ların ellerindeki isteği bitirmesini bekler
+    fn shutdown_signal(&self) -> &str {
+        "QUIT"
+    }
+
+    // SIGUSR2 yapılandırmayı ve php.ini'yi yeniden okuyup worker'ları nazikçe yeniler
+    fn reload_signal(&self) -> Option<&str> {
+        Some("USR2")
+    }
+
+    fn print_endpoints(&self, _port: Option<u16>) {
+        println!("   {} {}", "🔌 FastCGI :".cyan().bold(), self.listen);
+    }
+}
@@ -0,0 +1,71 @@
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use super::{Service, ServiceContext};
+use crate::core::settings::AppSettings;
+
+/// `*.test` alan adlarını site backend'lerine yönlendiren yerleşik reverse proxy.
+/// Ayrı bir ikili yoktur; FAMPP kendini gizli `serve proxy` komutuyla başlatır.
+pub struct ProxyService {
+    hosts: Vec<String>,
+    port: u16,
+    https_port: Option<u16>,
+}
+
+impl ProxyService {
+    pub fn new(settings: &AppSettings) -> Self {
+        Self {
+            hosts: crate::core::proxy::routes(settings).into_keys().collect(),
+            port: settings.proxy.port,
+            https_port: settings.proxy.https.then_some(settings.proxy.https_port),
+        }
+    }
+}
+
+impl Service for ProxyService {
+    fn name(&self) -> &str {
+        "proxy"
+    }
+
+    fn binary(&self, _base_path: &Path) -> Option<PathBuf> {
+        std::env::current_exe().ok()
+    }
+
+    fn default_port(&self, settings: &AppSettings) -> Option<u16> {
+        Some(settings.proxy.port)
+    }
+
+    fn args(&self, ctx: &ServiceContext, _bin_path: &Path, port: Option<u16>) -> Vec<String> {
+        let port = port.expect("Proxy her zaman bir porta sahiptir");
+        let mut args = vec![
+            "serve".to_string(),
+            "proxy".to_string(),
+            "--bind".to_string(),
+            ctx.bind.to_string(),
+            "--port".to_string(),
+            port.to_string(),
+        ];
+
+        if let Some(https_port) = self.https_port {
+            args.push("--tls-port".to_string());
+            args.push(https_port.to_string());
+        }
+
+        args
+    }
+
+    fn print_endpoints(&self, port: Option<u16>) {
+        let port = port.unwrap_or(self.port);
+        let suffix = |port: u16, default: u16| if port == default { String::new() } else { format!(":{}", port) };
+
+        for host in &self.hosts {
+            match self.https_port {
+                Some(https_port) => println!(
+                    "   {} http://{}{}  https://{}{}",
+                    "🔀 Host :".cyan().bold(), host, suffix(port, 80), host, suffix(https_port, 443)
+                ),
+                None => println!("   {} http://{}{}", "🔀 Host :".cyan().bold(), host, suffix(port, 80)),
+            }
+        }
+    }
+}
@@ -1,11 +1,30 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use crate::core::registry::PACKAGES;
+
+// FAMPP'ın kendi arka plan süreçleri; PID ve log dosyaları bu adlarla tutulur
+const INTERNAL_SERVICES: [&str; 3] = ["supervisor", "proxy", "dns"];
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AppSettings {
     pub language: String,
     pub ports: Ports,
+    #[serde(default)]
+    pub services: HashMap<String, ServiceSettings>,
+    #[serde(default)]
+    pub sites: BTreeMap<String, SiteSettings>,
+    #[serde(default)]
+    pub fpm: FpmSettings,
+    #[serde(default)]
+    pub proxy: ProxySettings,
+    #[serde(default)]
+    pub dns: DnsSettings,
+    // Ayarların okunduğu FAMPP dizini (`~/.fampp`); servisler socket ve yapılandırma yollarını buradan kurar
+    #[serde(skip)]
+    pub base_path: PathBuf,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -14,30 +33,342 @@ pub struct Ports {
     pub mysql: u16,
 }
 
+/// `config.toml` içindeki `[sites.<ad>]` bölümü: kendi PHP örneğiyle (`php-<ad>`) sunulan bir proje.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SiteSettings {
+    pub root: PathBuf,
+    // Boşsa [ports] altındaki PHP portundan itibaren ilk boş port kullanılır
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    // Reverse proxy'nin bu siteye yönlendireceği alan adı (varsayılan: <ad>.<tld>, örn: shop.test)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    // Sitenin PHP sürümü (örn: "8.3.10"); `fampp install php --version <sürüm>` ile kurulur, boşsa varsayılan PHP
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub php: Option<String>,
+    // Kendi router betiği; verilirse FAMPP'ın yönettiği router yerine bu kullanılır
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub router: Option<PathBuf>,
+    // Doluysa FAMPP'ın router'ı statik olmayan her isteği bu betiğe yönlendirir (örn: "index.php")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub front_controller: Option<String>,
+    // Yalnızca bu sitede php.ini'yi ezen ayarlar (`-d anahtar=değer` olarak verilir)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ini: BTreeMap<String, String>,
+}
+
+/// `config.toml` içindeki `[proxy]` bölümü: `*.test` alan adlarını site backend'lerine yönlendiren reverse proxy.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ProxySettings {
+    pub port: u16,
+    // Açıksa proxy ayrıca `https_port` üzerinde FAMPP'ın yerel CA'sıyla imzalanmış sertifikalarla TLS sunar
+    pub https: bool,
+    pub https_port: u16,
+    // Sitelere otomatik verilen alan adlarının uzantısı
+    pub tld: String,
+    // Site olmayan backend'ler: alan adı -> servis adı ya da "host:port" (örn: "api.test" = "127.0.0.1:5173")
+    pub routes: BTreeMap<String, String>,
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        Self {
+            port: 80,
+            https: true,
+            https_port: 443,
+            tld: "test".to_string(),
+            routes: BTreeMap::new(),
+        }
+    }
+}
+
+/// `config.toml` içindeki `[dns]` bölümü: `*.<tld>` sorgularını yanıtlayan yerel DNS sunucusu.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DnsSettings {
+    // 53 yerine yüksek bir port; işletim sistemi yalnızca <tld> için buraya yönlendirilir
+    pub port: u16,
+    // Sorgulara verilen adres (reverse proxy'nin dinlediği yer)
+    pub address: String,
+}
+
+impl Default for DnsSettings {
+    fn default() -> Self {
+        Self {
+            port: 5300,
+            address: "127.0.0.1".to_string(),
+        }
+    }
+}
+
+/// `config.toml` içindeki `[fpm]` bölümü: php-fpm'in FAMPP tarafından üretilen havuz (pool) ayarları.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct FpmSettings {
+    pub listen: FpmListen,
+    // Yalnızca `listen = "tcp"` iken kullanılır
+    pub port: u16,
+    // php-fpm root olarak başlatıldığında worker'ların düşürüleceği kullanıcı ve grup
+    pub user: Option<String>,
+    pub group: Option<String>,
+    pub max_children: u32,
+    // Havuz bölümüne olduğu gibi yazılan ek ayarlar (örn: "pm.max_requests" = "500")
+    pub pool: BTreeMap<String, String>,
+}
+
+impl Default for FpmSettings {
+    fn default() -> Self {
+        Self {
+            listen: FpmListen::default(),
+            port: 9000,
+            user: None,
+            group: None,
+            max_children: 5,
+            pool: BTreeMap::new(),
+        }
+    }
+}
+
+/// php-fpm'in FastCGI isteklerini dinlediği yer.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FpmListen {
+    // `~/.fampp/data/php-fpm.sock`
+    #[default]
+    Socket,
+    Tcp,
+}
+
+impl SiteSettings {
+    /// Sitenin alan adı: `domain` verilmişse o, yoksa `<ad>.<tld>` (örn: shop.test).
+    pub fn domain(&self, name: &str, tld: &str) -> String {
+        self.domain.clone().unwrap_or_else(|| format!("{}.{}", name, tld.trim_matches('.'))).to_lowercase()
+    }
+}
+
+/// `config.toml` içindeki `[services.<ad>]` bölümü.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ServiceSettings {
+    pub restart: RestartPolicy,
+    pub readiness: Probe,
+    // Servisin hazır olması için beklenecek en uzun süre (saniye)
+    pub ready_timeout: u64,
+    // FAMPP'ın ürettiği argümanların sonuna eklenen ek argümanlar
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    // Göreli yollar `fampp`ın çalıştırıldığı dizine değil, config.toml'un dizinine göredir
+    pub cwd: Option<PathBuf>,
+    // `env` tablosundaki değerler .env dosyasındakileri ezer
+    pub env_file: Option<PathBuf>,
+    // Doluysa bu bölüm FAMPP'ın paketlemediği kullanıcı tanımlı bir servistir (örn: queue worker)
+    pub command: Option<String>,
+    pub port: Option<u16>,
+    // Port doluysa boş port aranacak aralık, örn: [8000, 8010] (varsayılan: portun ardından 100 port)
+    pub port_range: Option<[u16; 2]>,
+    // Servisin dinleyeceği adres (varsayılan: 127.0.0.1, tüm ağ için 0.0.0.0)
+    pub bind: Option<String>,
+    // Bu servisten önce başlatılıp hazır olması beklenecek servisler
+    pub depends_on: Vec<String>,
+}
+
+impl Default for ServiceSettings {
+    fn default() -> Self {
+        Self {
+            restart: RestartPolicy::default(),
+            readiness: Probe::default(),
+            ready_timeout: 30,
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            cwd: None,
+            env_file: None,
+            command: None,
+            port: None,
+            port_range: None,
+            bind: None,
+            depends_on: Vec::new(),
+        }
+    }
+}
+
+/// Servisin istek kabul etmeye hazır olduğunu anlamak için kullanılan yoklama.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Probe {
+    // Port boş bırakılırsa servisin gerçekte dinlediği port kullanılır
+    Tcp {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        port: Option<u16>,
+    },
+    Http {
+        #[serde(default = "default_http_path")]
+        path: String,
+        #[serde(default = "default_http_status")]
+        status: u16,
+    },
+    Command {
+        run: String,
+    },
+    // Süreç ayakta olduğu sürece hazır kabul edilir (portu olmayan worker'lar için)
+    None,
+}
+
+impl Default for Probe {
+    fn default() -> Self {
+        Probe::Tcp { port: None }
+    }
+}
+
+fn default_http_path() -> String {
+    "/".to_string()
+}
+
+fn default_http_status() -> u16 {
+    200
+}
+
+/// Süpervizörün çöken bir servise nasıl davranacağı.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    Never,
+    #[default]
+    OnFailure,
+    Always,
+}
+
 impl AppSettings {
-    pub fn load_or_create(base_path: &PathBuf) -> Self {
+    /// Dosya yoksa varsayılanlarla oluşturur. Var ama okunamıyorsa hata döner; siteler ve servisler de bu
+    /// dosyada tutulduğu için hatalı bir dosyanın üzerine asla varsayılanlar yazılmaz.
+    pub fn load_or_create(base_path: &Path) -> Result<Self, String> {
         let config_file = base_path.join("config.toml");
-        
+
         if config_file.exists() {
-            if let Ok(contents) = fs::read_to_string(&config_file) {
-                if let Ok(settings) = toml::from_str(&contents) {
-                    return settings;
-                }
-            }
+            return Self::read(base_path).map_err(|e| format!("{}: {}", config_file.display(), e));
         }
-        
+
         let default_settings = AppSettings {
             language: "en".to_string(),
             ports: Ports {
                 php: 8000,
                 mysql: 3306,
-            }
+            },
+            services: HashMap::new(),
+            sites: BTreeMap::new(),
+            fpm: FpmSettings::default(),
+            proxy: ProxySettings::default(),
+            dns: DnsSettings::default(),
+            base_path: base_path.to_path_buf(),
         };
-        
+
         if let Ok(toml_string) = toml::to_string(&default_settings) {
             let _ = fs::write(config_file, toml_string);
         }
-        
-        default_settings
+
+        Ok(default_settings)
     }
-}
\ No newline at end of file
+
+    /// Dosyayı olduğu gibi okur; `load_or_create`'in aksine dosya yoksa varsayılanlarla oluşturmaz.
+    /// Arka planda config.toml'u izleyen sunucular yarım kalmış bir düzenlemeyi bozmamak için bunu kullanır.
+    pub fn read(base_path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(base_path.join("config.toml")).map_err(|e| e.to_string())?;
+        let settings: AppSettings = toml::from_str(&content).map_err(|e| e.to_string())?;
+
+        // `command` taşımayan bölümler yerleşik servislerin ayarıdır; yalnızca kullanıcı servisleri denetlenir
+        let mut reserved: Vec<&String> = settings.services.iter()
+            .filter(|(name, svc)| svc.command.is_some() && is_reserved_service(name))
+            .map(|(name, _)| name)
+            .collect();
+        reserved.sort();
+        if let Some(name) = reserved.first() {
+            return Err(format!("[services.{}]: '{}' is reserved for FAMPP's own services, choose another name", name, name));
+        }
+
+        Ok(AppSettings { base_path: base_path.to_path_buf(), ..settings })
+    }
+
+    /// `config.toml` dosyasını tablo olarak okur, verilen düzenlemeyi uygular ve geri yazar.
+    pub fn update_config<F>(base_path: &Path, edit: F) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnOnce(&mut toml::Table),
+    {
+        let config_file = base_path.join("config.toml");
+        let mut table: toml::Table = toml::from_str(&fs::read_to_string(&config_file)?)?;
+        edit(&mut table);
+        fs::write(config_file, toml::to_string(&table)?)?;
+        Ok(())
+    }
+
+    /// Siteyi `[sites.<ad>]` bölümüne yazar; aynı adlı site varsa üzerine yazılır.
+    pub fn save_site(base_path: &Path, name: &str, site: &SiteSettings) -> Result<(), Box<dyn std::error::Error>> {
+        let value = toml::Value::try_from(site)?;
+        Self::update_config(base_path, |table| {
+            let sites = table
+                .entry("sites")
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let Some(sites) = sites.as_table_mut() {
+                sites.insert(name.to_string(), value);
+            }
+        })
+    }
+
+    /// `[sites.<ad>]` bölümünü siler; site tanımlı değilse `false` döner.
+    pub fn remove_site(base_path: &Path, name: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut removed = false;
+        Self::update_config(base_path, |table| {
+            if let Some(sites) = table.get_mut("sites").and_then(|sites| sites.as_table_mut()) {
+                removed = sites.remove(name).is_some();
+            }
+        })?;
+        Ok(removed)
+    }
+
+    pub fn service(&self, name: &str) -> ServiceSettings {
+        self.services.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// Ad yerleşik bir pakete, FAMPP'ın kendi süreçlerine (süpervizör, proxy, DNS) ya da site örneklerine (`php-<ad>`)
+/// mi ayrılmış? Bu adlarla tanımlanan kullanıcı servisleri gerçek servisle PID ve log dosyalarını paylaşırdı.
+pub fn is_reserved_service(name: &str) -> bool {
+    PACKAGES.contains(&name) || INTERNAL_SERVICES.contains(&name) || name.starts_with("php-")
+}
+
+/// `~/` ile başlayan yolları kullanıcının ev dizinine genişletir.
+pub fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// config.toml'da yazılan bir yolu çözer: `~/` genişletilir, göreli yollar FAMPP dizinine (`~/.fampp`) bağlanır.
+/// Böylece aynı servis `fampp start` hangi dizinde çalıştırılırsa çalıştırılsın aynı dosyaları görür.
+pub fn config_path(base_path: &Path, path: &Path) -> PathBuf {
+    let path = expand_home(path);
+    if path.is_relative() { base_path.join(path) } else { path }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_service_names() {
+        for name in ["php", "php-fpm", "mysql", "supervisor", "proxy", "dns", "php-shop"] {
+            assert!(is_reserved_service(name), "{}", name);
+        }
+        for name in ["queue", "vite", "phpmyadmin"] {
+            assert!(!is_reserved_service(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn config_path_resolves_relative_paths_against_the_fampp_directory() {
+        let base_path = Path::new("/home/dev/.fampp");
+        assert_eq!(config_path(base_path, Path::new("env/app.env")), base_path.join("env/app.env"));
+        let absolute = std::env::temp_dir().join("app");
+        assert_eq!(config_path(base_path, &absolute), absolute);
+    }
+}
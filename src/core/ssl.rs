@@ -0,0 +1,147 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration as StdDuration, SystemTime};
+
+use rcgen::{
+    BasicConstraints, CertificateParams, DistinguishedName, DnType, ExtendedKeyUsagePurpose, IsCa, Issuer,
+    KeyPair, KeyUsagePurpose,
+};
+use time::{Duration, OffsetDateTime};
+
+// Kök sertifika uzun ömürlüdür; yenilenirse tarayıcıya yeniden güvenilmesi gerekir
+const CA_DAYS: i64 = 3650;
+// Tarayıcılar (özellikle Safari) 398 günden uzun site sertifikalarını reddeder
+const CERT_DAYS: i64 = 397;
+// Süresi dolmadan bu kadar gün önce site sertifikası kendiliğinden yenilenir
+const RENEW_BEFORE_DAYS: i64 = 30;
+
+const CA_NAME: &str = "FAMPP Local CA";
+
+/// Sertifikaların tutulduğu `~/.fampp/ssl` dizini.
+pub fn dir(base_path: &Path) -> PathBuf {
+    base_path.join("ssl")
+}
+
+/// Tarayıcıya ve işletim sistemine güvenilir olarak eklenecek kök sertifika.
+pub fn ca_path(base_path: &Path) -> PathBuf {
+    dir(base_path).join("ca.pem")
+}
+
+fn ca_key_path(base_path: &Path) -> PathBuf {
+    dir(base_path).join("ca-key.pem")
+}
+
+/// Alan adının sertifikası ve anahtarı (örn: `certs/shop.test.pem`, `certs/shop.test-key.pem`).
+pub fn cert_paths(base_path: &Path, domain: &str) -> (PathBuf, PathBuf) {
+    let certs = dir(base_path).join("certs");
+    (certs.join(format!("{}.pem", domain)), certs.join(format!("{}-key.pem", domain)))
+}
+
+// Kök sertifikanın kimliği; imzalarken diskteki sertifikayı ayrıştırmak yerine aynı parametreler yeniden kurulur
+fn ca_params() -> CertificateParams {
+    let mut params = CertificateParams::default();
+    let mut name = DistinguishedName::new();
+    name.push(DnType::CommonName, CA_NAME);
+    name.push(DnType::OrganizationName, "FAMPP");
+    params.distinguished_name = name;
+    params.is_ca = IsCa::Ca(BasicConstraints::Constrained(0));
+    params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign, KeyUsagePurpose::DigitalSignature];
+    params
+}
+
+/// Kök sertifikayı yoksa üretir; varsa dokunmaz. Yeni üretildiyse `true` döner.
+pub fn ensure_ca(base_path: &Path) -> Result<bool, String> {
+    if ca_path(base_path).exists() && ca_key_path(base_path).exists() {
+        return Ok(false);
+    }
+
+    let key = KeyPair::generate().map_err(|e| e.to_string())?;
+    let mut params = ca_params();
+    let now = OffsetDateTime::now_utc();
+    params.not_before = now - Duration::days(1);
+    params.not_after = now + Duration::days(CA_DAYS);
+
+    let cert = params.self_signed(&key).map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(dir(base_path)).map_err(|e| e.to_string())?;
+    write_private(&ca_key_path(base_path), &key.serialize_pem())?;
+    fs::write(ca_path(base_path), cert.pem()).map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+/// Alan adı ve tüm alt alan adları (`*.shop.test`) için geçerli sertifikayı hazırlar.
+/// Sertifika yoksa, yenileme zamanı geldiyse ya da kök sertifikadan eskiyse yeniden imzalanır.
+pub fn ensure_cert(base_path: &Path, domain: &str) -> Result<(PathBuf, PathBuf), String> {
+    ensure_ca(base_path)?;
+    let (cert_path, key_path) = cert_paths(base_path, domain);
+
+    if !needs_renewal(&cert_path, &ca_path(base_path)) && key_path.exists() {
+        return Ok((cert_path, key_path));
+    }
+
+    let ca_key = KeyPair::from_pem(&fs::read_to_string(ca_key_path(base_path)).map_err(|e| e.to_string())?)
+        .map_err(|e| e.to_string())?;
+    let issuer = Issuer::new(ca_params(), ca_key);
+
+    let mut params = CertificateParams::new(vec![domain.to_string(), format!("*.{}", domain)])
+        .map_err(|e| e.to_string())?;
+    let mut name = DistinguishedName::new();
+    name.push(DnType::CommonName, domain);
+    params.distinguished_name = name;
+    params.key_usages = vec![KeyUsagePurpose::DigitalSignature, KeyUsagePurpose::KeyEncipherment];
+    params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+    params.use_authority_key_identifier_extension = true;
+
+    let now = OffsetDateTime::now_utc();
+    params.not_before = now - Duration::days(1);
+    params.not_after = now + Duration::days(CERT_DAYS);
+
+    let key = KeyPair::generate().map_err(|e| e.to_string())?;
+    let cert = params.signed_by(&key, &issuer).map_err(|e| e.to_string())?;
+
+    if let Some(certs) = cert_path.parent() {
+        fs::create_dir_all(certs).map_err(|e| e.to_string())?;
+    }
+    write_private(&key_path, &key.serialize_pem())?;
+    fs::write(&cert_path, cert.pem()).map_err(|e| e.to_string())?;
+    Ok((cert_path, key_path))
+}
+
+// Sertifikanın yazıldığı an imzalandığı andır; geçerlilik süresi dosyanın yaşından hesaplanır
+fn needs_renewal(cert_path: &Path, ca_path: &Path) -> bool {
+    let modified = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+    let Some(issued) = modified(cert_path) else {
+        return true;
+    };
+
+    let renew_after = StdDuration::from_secs(((CERT_DAYS - RENEW_BEFORE_DAYS) * 86_400) as u64);
+    let expiring = SystemTime::now().duration_since(issued).map(|age| age >= renew_after).unwrap_or(false);
+    let stale = modified(ca_path).is_some_and(|ca| ca > issued);
+    expiring || stale
+}
+
+// Özel anahtarlar yalnızca kullanıcı tarafından okunabilir olmalı
+fn write_private(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| e.to_string())?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Kök sertifikayı işletim sisteminin güven deposuna ekleyen kabuk komutu (yönetici yetkisi gerekir).
+pub fn trust_command(base_path: &Path) -> String {
+    let ca = ca_path(base_path).display().to_string();
+
+    if cfg!(target_os = "macos") {
+        format!("sudo security add-trusted-cert -d -r trustRoot -k /Library/Keychains/System.keychain '{}'", ca)
+    } else if cfg!(windows) {
+        format!("certutil -addstore -f ROOT \"{}\"", ca)
+    } else {
+        format!("sudo cp '{}' /usr/local/share/ca-certificates/fampp.crt && sudo update-ca-certificates", ca)
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::thread;
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+/// Bir servisin süreç ağacının (ana süreç + worker'lar) toplam kaynak kullanımı.
+pub struct TreeStats {
+    pub cpu: f32,
+    pub memory: u64,
+    pub uptime: u64,
+    pub children: usize,
+}
+
+/// Süreç tablosunun anlık görüntüsü; CPU yüzdesi iki ölçüm arasındaki farktan hesaplanır.
+pub struct StatsCollector {
+    system: System,
+}
+
+impl StatsCollector {
+    /// İlk ölçümü alır ve CPU kullanımı anlamlı olsun diye kısa bir süre bekleyip tekrar ölçer.
+    pub fn new() -> Self {
+        let mut collector = Self { system: System::new() };
+        collector.refresh();
+        thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        collector.refresh();
+        collector
+    }
+
+    pub fn refresh(&mut self) {
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing().without_tasks().with_cpu().with_memory(),
+        );
+    }
+
+    pub fn tree(&self, pid: u32) -> Option<TreeStats> {
+        let root = self.system.process(Pid::from_u32(pid))?;
+
+        let mut by_parent: HashMap<Pid, Vec<Pid>> = HashMap::new();
+        for (child, process) in self.system.processes() {
+            if let Some(parent) = process.parent()
+                && process.thread_kind().is_none()
+            {
+                by_parent.entry(parent).or_default().push(*child);
+            }
+        }
+
+        let mut stats = TreeStats {
+            cpu: root.cpu_usage(),
+            memory: root.memory(),
+            uptime: root.run_time(),
+            children: 0,
+        };
+
+        let mut queue = by_parent.get(&root.pid()).cloned().unwrap_or_default();
+        while let Some(child) = queue.pop() {
+            if let Some(process) = self.system.process(child) {
+                stats.cpu += process.cpu_usage();
+                stats.memory += process.memory();
+                stats.children += 1;
+            }
+            if let Some(grandchildren) = by_parent.get(&child) {
+                queue.extend(grandchildren);
+            }
+        }
+
+        Some(stats)
+    }
+}
+
+/// Bayt cinsinden belleği okunabilir hale getirir (örn: 42.3 MB).
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Saniyeyi kısa bir süre ifadesine çevirir (örn: 2d 4h, 3h 12m, 45s).
+pub fn format_duration(secs: u64) -> String {
+    let (days, hours, mins) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else if mins > 0 {
+        format!("{}m {}s", mins, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
@@ -0,0 +1,124 @@
+use serde::Serialize;
+use std::path::Path;
+
+use crate::core::health;
+use crate::core::network;
+use crate::core::process::ProcessManager;
+use crate::core::services;
+use crate::core::settings::AppSettings;
+use crate::core::stats::StatsCollector;
+use crate::core::supervisor::CrashHistory;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceState {
+    Running,
+    Unhealthy,
+    // PID dosyası duruyor ama süreç ölmüş: FAMPP dışında bir şey onu sonlandırdı
+    Crashed,
+    Stopped,
+}
+
+impl ServiceState {
+    /// `status <svc> --quiet` için LSB çıkış kodu: 0 sağlıklı, 1 çökmüş ya da sağlıksız, 3 durmuş.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            ServiceState::Running => 0,
+            ServiceState::Unhealthy | ServiceState::Crashed => 1,
+            ServiceState::Stopped => 3,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StackState {
+    Running,
+    Degraded,
+    Stopped,
+}
+
+impl StackState {
+    /// LSB geleneğine uygun çıkış kodu: 0 her şey yolunda, 1 sorunlu servis var, 3 hiçbir şey çalışmıyor.
+    pub fn exit_code(self) -> i32 {
+        match self {
+            StackState::Running => 0,
+            StackState::Degraded => 1,
+            StackState::Stopped => 3,
+        }
+    }
+}
+
+/// `status --json` çıktısındaki tek bir servis satırı.
+#[derive(Serialize, Debug)]
+pub struct ServiceStatus {
+    pub name: String,
+    pub state: ServiceState,
+    pub pid: Option<u32>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub cpu: Option<f32>,
+    pub memory: Option<u64>,
+    pub uptime: Option<u64>,
+    pub children: Option<usize>,
+    pub crashes: u32,
+    pub restarts: u32,
+}
+
+/// Tüm ortamın anlık durumu; hem durum tablosu hem de JSON çıktısı bundan üretilir.
+#[derive(Serialize, Debug)]
+pub struct StackStatus {
+    pub state: StackState,
+    pub supervisor: bool,
+    pub services: Vec<ServiceStatus>,
+}
+
+/// Kaynak kullanımı gerekmiyorsa (örn: `--quiet`) `stats` boş geçilerek CPU örneklemesi atlanır.
+pub fn collect(base_path: &Path, settings: &AppSettings, pm: &ProcessManager, stats: Option<&StatsCollector>) -> StackStatus {
+    let mut services = Vec::new();
+
+    for service in services::all(settings) {
+        let name = service.name();
+        let pid = pm.read_pid(name);
+        // Eğer .port dosyası yoksa config.toml'daki varsayılana dön
+        let port = pm.read_port(name).or(service.default_port(settings));
+        let host = pm.read_bind(name)
+            .or(settings.service(name).bind)
+            .unwrap_or_else(|| network::LOOPBACK.to_string());
+        let usage = pid.zip(stats).and_then(|(pid, stats)| stats.tree(pid));
+        let history = CrashHistory::load(base_path, name);
+
+        let state = match pid {
+            None => ServiceState::Stopped,
+            Some(_) if !pm.is_running(name) => ServiceState::Crashed,
+            Some(_) => match health::check(&service.readiness(settings), network::probe_host(&host), port.unwrap_or_default()) {
+                Ok(_) => ServiceState::Running,
+                Err(_) => ServiceState::Unhealthy,
+            },
+        };
+
+        services.push(ServiceStatus {
+            name: name.to_string(),
+            state,
+            pid,
+            host: pid.and(Some(host)),
+            port: pid.and(port),
+            cpu: usage.as_ref().map(|u| u.cpu),
+            memory: usage.as_ref().map(|u| u.memory),
+            uptime: usage.as_ref().map(|u| u.uptime),
+            children: usage.as_ref().map(|u| u.children),
+            crashes: history.crashes,
+            restarts: history.restarts,
+        });
+    }
+
+    let state = if services.iter().all(|s| s.state == ServiceState::Stopped) {
+        StackState::Stopped
+    } else if services.iter().any(|s| matches!(s.state, ServiceState::Unhealthy | ServiceState::Crashed)) {
+        StackState::Degraded
+    } else {
+        StackState::Running
+    };
+
+    StackStatus { state, supervisor: pm.is_running("supervisor"), services }
+}
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::core::process::{self, ProcessManager};
+use crate::core::settings::{AppSettings, RestartPolicy};
+
+const TICK: Duration = Duration::from_secs(1);
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+// Bu kadar süre ayakta kalan bir servisin bekleme süresi sıfırlanır
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+const HISTORY_LIMIT: usize = 10;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CrashRecord {
+    pub at: u64,
+    pub exit_code: Option<i32>,
+}
+
+/// Bir servisin `data/supervisor/<ad>.json` dosyasında tutulan çökme geçmişi.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct CrashHistory {
+    pub crashes: u32,
+    pub restarts: u32,
+    pub recent: Vec<CrashRecord>,
+}
+
+impl CrashHistory {
+    fn path(base_path: &Path, name: &str) -> PathBuf {
+        base_path.join("data").join("supervisor").join(format!("{}.json", name))
+    }
+
+    pub fn load(base_path: &Path, name: &str) -> Self {
+        fs::read_to_string(Self::path(base_path, name))
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, base_path: &Path, name: &str) {
+        let path = Self::path(base_path, name);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, json);
+        }
+    }
+
+    pub fn last(&self) -> Option<&CrashRecord> {
+        self.recent.last()
+    }
+}
+
+struct Pending {
+    port: Option<u16>,
+    bind: Option<String>,
+    next_attempt: Instant,
+}
+
+struct Backoff {
+    delay: Duration,
+    started_at: Instant,
+}
+
+/// Yönetilen servisleri izler ve `restart` politikasına göre çökenleri yeniden başlatır.
+pub struct Supervisor<'a> {
+    base_path: &'a Path,
+    pm: &'a ProcessManager,
+    settings: &'a AppSettings,
+    pending: HashMap<String, Pending>,
+    backoff: HashMap<String, Backoff>,
+}
+
+impl<'a> Supervisor<'a> {
+    pub fn new(base_path: &'a Path, pm: &'a ProcessManager, settings: &'a AppSettings) -> Self {
+        Self {
+            base_path,
+            pm,
+            settings,
+            pending: HashMap::new(),
+            backoff: HashMap::new(),
+        }
+    }
+
+    /// Sonsuz izleme döngüsü. `restart` servisi verilen port ve adresle yeniden başlatıp PID döndürmelidir.
+    pub fn run<F>(&mut self, services: &[&str], restart: F) -> !
+    where
+        F: Fn(&str, Option<u16>, Option<&str>) -> Option<u32>,
+    {
+        println!("👁️  Supervisor watching: {}", services.join(", "));
+
+        loop {
+            for svc in services {
+                self.tick(svc, &restart);
+            }
+            thread::sleep(TICK);
+        }
+    }
+
+    fn tick<F>(&mut self, svc: &str, restart: &F)
+    where
+        F: Fn(&str, Option<u16>, Option<&str>) -> Option<u32>,
+    {
+        // Kullanıcı servisi elle başlattıysa bekleyen yeniden başlatmayı iptal et
+        if self.pending.contains_key(svc) && self.pm.is_running(svc) {
+            self.pending.remove(svc);
+        }
+
+        if let Some(pending) = self.pending.get(svc) {
+            if Instant::now() < pending.next_attempt {
+                return;
+            }
+
+            let port = pending.port;
+            let bind = pending.bind.clone();
+            let mut history = CrashHistory::load(self.base_path, svc);
+            println!("🔁 Restarting {} (attempt #{})", svc, history.restarts + 1);
+
+            history.restarts += 1;
+            history.save(self.base_path, svc);
+
+            let delay = self.next_delay(svc);
+            if restart(svc, port, bind.as_deref()).is_some() {
+                self.pending.remove(svc);
+                self.backoff.insert(svc.to_string(), Backoff { delay, started_at: Instant::now() });
+            } else if let Some(pending) = self.pending.get_mut(svc) {
+                pending.next_attempt = Instant::now() + delay;
+                self.backoff.insert(svc.to_string(), Backoff { delay, started_at: Instant::now() });
+            }
+            return;
+        }
+
+        let exit = self.pm.try_wait(svc);
+
+        let Some(pid) = self.pm.read_pid(svc) else {
+            return;
+        };
+
+        if exit.is_none() && process::is_alive(pid) {
+            return;
+        }
+
+        // Bizim çocuğumuz değilse çıkış kodunu bilemeyiz; beklenmedik ölüm hata sayılır
+        let exit_code = exit.map(exit_code);
+        let failed = exit.is_none_or(|status| !status.success());
+        let port = self.pm.read_port(svc);
+        let bind = self.pm.read_bind(svc);
+
+        // Ana süreç ölmüş olsa bile geride kalan worker'ları temizle
+        process::kill_tree(pid);
+        self.pm.clear(svc);
+
+        let mut history = CrashHistory::load(self.base_path, svc);
+        if failed {
+            history.crashes += 1;
+            history.recent.push(CrashRecord { at: now_secs(), exit_code });
+            if history.recent.len() > HISTORY_LIMIT {
+                history.recent.remove(0);
+            }
+            history.save(self.base_path, svc);
+        }
+
+        println!(
+            "💥 {} (PID {}) exited (code: {})",
+            svc,
+            pid,
+            exit_code.map(|c| c.to_string()).unwrap_or_else(|| "unknown".to_string())
+        );
+
+        let should_restart = match self.settings.service(svc).restart {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure => failed,
+            RestartPolicy::Always => true,
+        };
+
+        if should_restart {
+            let delay = self.current_delay(svc);
+            println!("⏳ Restarting {} in {}s", svc, delay.as_secs());
+            self.pending.insert(svc.to_string(), Pending { port, bind, next_attempt: Instant::now() + delay });
+        }
+    }
+
+    fn current_delay(&self, svc: &str) -> Duration {
+        match self.backoff.get(svc) {
+            Some(b) if b.started_at.elapsed() < STABLE_AFTER => b.delay,
+            _ => INITIAL_BACKOFF,
+        }
+    }
+
+    fn next_delay(&self, svc: &str) -> Duration {
+        (self.current_delay(svc) * 2).min(MAX_BACKOFF)
+    }
+}
+
+fn exit_code(status: ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+
+    // Sinyalle ölen süreçler için kabuk geleneği: 128 + sinyal numarası
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    -1
+}
+
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
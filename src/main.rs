@@ -1,21 +1,35 @@
 mod core;
 
-use std::path::PathBuf;
-use std::path::Path;
-use std::fs;
 use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
 use std::time::Duration;
-use colored::Colorize;
-use crate::core::process::ProcessManager;
+use colored::{Color, Colorize};
+use crate::core::health;
+use crate::core::process::{self, LaunchSpec, ProcessManager};
 use crate::core::config::ConfigManager;
-use crate::core::settings::AppSettings;
+use crate::core::deps::DependencyGraph;
+use crate::core::settings::{config_path, expand_home, is_reserved_service, AppSettings, SiteSettings};
 use crate::core::locale::I18n;
-use crate::core::registry::get_package_info;
+use crate::core::registry::{self, get_package_info, get_php_extension_info, PHP_EXTENSIONS};
 use crate::core::downloader;
 use crate::core::extractor;
+use crate::core::logmux::LogMux;
+use crate::core::network;
+use crate::core::phpext;
+use crate::core::phpini;
+use crate::core::dns;
+use crate::core::hosts;
+use crate::core::proxy;
+use crate::core::ssl;
+use crate::core::services::{self, PhpService, Service, ServiceContext};
+use crate::core::stats::{self, StatsCollector};
+use crate::core::status::{self as stack_status, ServiceState, ServiceStatus, StackStatus};
+use crate::core::supervisor::{self, CrashHistory, Supervisor};
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser)]
 #[command(name = "fampp")]
@@ -25,6 +39,10 @@ use clap::{Parser, Subcommand};
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    #[arg(long, global = true, help = "Betikler ve editör eklentileri için JSON çıktı üret")]
+    json: bool,
+    #[arg(long, global = true, help = "Renkli çıktıyı kapat")]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -37,36 +55,902 @@ enum Commands {
     Start {
         #[arg(short, long)]
         all: bool,
+        #[arg(long, help = "Servisi tüm ağ arayüzlerinde (0.0.0.0) dinlet ve LAN adreslerini göster")]
+        lan: bool,
+        #[arg(long, help = "Adlandırılmış bir PHP sitesini kendi portunda başlat (örn: shop)")]
+        site: Option<String>,
+        #[arg(long, requires = "site", help = "Sitenin kök dizini; verilirse site config.toml'a kaydedilir")]
+        root: Option<PathBuf>,
+        #[arg(long, requires = "site", help = "Her isteğin önce geçeceği router betiği")]
+        router: Option<PathBuf>,
+        #[arg(long, requires = "site", help = "Statik olmayan istekleri bu betiğe yönlendir (örn: index.php)")]
+        front_controller: Option<String>,
         package: Option<String>,
     },
     Stop {
+        #[arg(short, long)]
+        all: bool,
+        #[arg(long, help = "Bu servise bağımlı çalışan servisleri de durdur")]
+        cascade: bool,
+        package: Option<String>,
+    },
+    #[command(about = "Servisi nazikçe durdurup aynı portla yeniden başlatır")]
+    Restart {
         #[arg(short, long)]
         all: bool,
         package: Option<String>,
     },
-    Status,
+    #[command(about = "Servisin yapılandırmasını kesinti olmadan yeniden yükler")]
+    Reload {
+        package: String,
+    },
+    #[command(about = "Tüm servisleri ön planda başlatır ve loglarını tek terminalde birleştirir")]
+    Up {
+        #[arg(help = "Başlatılacak servisler (boş bırakılırsa kurulu olanların hepsi)")]
+        services: Vec<String>,
+    },
+    Status {
+        #[arg(help = "Yalnızca bu servisin durumunu göster (örn: php, mysql)")]
+        service: Option<String>,
+        #[arg(short, long, help = "Etkin komut satırlarını da göster")]
+        verbose: bool,
+        #[arg(short, long, help = "Tabloyu her saniye yerinde yenile")]
+        watch: bool,
+        #[arg(short, long, help = "Hiçbir şey yazma, yalnızca çıkış koduyla bildir (0 sağlıklı, 1 sorunlu, 3 durmuş)")]
+        quiet: bool,
+    },
+    #[command(about = "Registry'deki paketleri ve kurulum durumlarını listeler")]
+    List,
     #[command(about = "Servisin anlık loglarını terminalde izler")]
     Logs {
         #[arg(help = "Paket adı (örn: php, mysql)")]
         package: String,
     },
+    #[command(about = "Çöken servisleri otomatik yeniden başlatan arka plan süpervizörünü yönetir")]
+    Supervisor {
+        #[arg(value_enum)]
+        action: SupervisorAction,
+    },
+    #[command(about = "PHP'ye özel ayarları yönetir (php.ini)")]
+    Php {
+        #[command(subcommand)]
+        command: PhpCommand,
+    },
+    #[command(about = "Diskin herhangi bir yerindeki projeleri site olarak yönetir")]
+    Site {
+        #[command(subcommand)]
+        action: SiteAction,
+    },
+    #[command(about = "Bulunulan dizini klasör adıyla site olarak kaydeder ve hemen sunar")]
+    Link {
+        #[arg(long, help = "Site adı (varsayılan: klasörün adı)")]
+        name: Option<String>,
+    },
+    #[command(about = "Bulunulan dizine bağlı siteyi durdurur ve kaydını siler")]
+    Unlink {
+        #[arg(long, help = "Bu dizin yerine adı verilen siteyi kaldır")]
+        name: Option<String>,
+    },
+    #[command(about = "Yerel HTTPS için FAMPP kök sertifikasını yönetir")]
+    Ssl {
+        #[command(subcommand)]
+        action: SslAction,
+    },
+    #[command(about = "Sistem hosts dosyasındaki FAMPP bloğunu yönetir")]
+    Hosts {
+        #[command(subcommand)]
+        action: HostsAction,
+    },
+    #[command(about = "Procfile'daki süreçleri config.toml'a özel servis olarak ekler")]
+    Import {
+        #[arg(default_value = "Procfile", help = "Procfile yolu")]
+        path: PathBuf,
+    },
+    // Süpervizörün arka planda çalıştırdığı asıl izleme döngüsü
+    #[command(hide = true)]
+    Supervise,
+    // FAMPP'ın kendi içinde çalıştırdığı sunucular (örn: reverse proxy); ProcessManager tarafından başlatılır
+    #[command(hide = true)]
+    Serve {
+        #[arg(value_enum)]
+        server: ServeTarget,
+        #[arg(long, default_value = network::LOOPBACK)]
+        bind: String,
+        #[arg(long)]
+        port: u16,
+        #[arg(long)]
+        tls_port: Option<u16>,
+    },
     Help,
 }
 
-fn find_executable(dir: &Path, bin_name: &str) -> Option<PathBuf> {
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() && path.file_name().and_then(|n| n.to_str()) == Some(bin_name) {
-                return Some(path);
-            } else if path.is_dir() {
-                if let Some(found) = find_executable(&path, bin_name) {
-                    return Some(found);
+#[derive(Subcommand)]
+enum PhpCommand {
+    #[command(about = "~/.fampp/config/php/php.ini ayarlarını yönetir")]
+    Ini {
+        #[command(subcommand)]
+        action: IniAction,
+    },
+    #[command(about = "PHP eklentilerini yönetir (xdebug, redis, imagick...)")]
+    Ext {
+        #[command(subcommand)]
+        action: ExtAction,
+    },
+    #[command(about = "Xdebug adım adım hata ayıklamayı açar ya da kapatır")]
+    Xdebug { state: Toggle },
+}
+
+#[derive(Subcommand)]
+enum ExtAction {
+    #[command(about = "Registry'deki ve kurulu eklentileri listeler")]
+    List,
+    #[command(about = "Eklentiyi indirir (gerekirse) ve php.ini'de etkinleştirir")]
+    Enable { name: String },
+    #[command(about = "Eklentiyi php.ini'de devre dışı bırakır")]
+    Disable { name: String },
+}
+
+#[derive(Subcommand)]
+enum SiteAction {
+    #[command(about = "Bir dizini site olarak kaydeder; aynı adlı site varsa ayarlarını günceller")]
+    Add {
+        name: String,
+        path: PathBuf,
+        #[arg(long, help = "Sitenin alan adı (varsayılan: <ad>.<tld>)")]
+        domain: Option<String>,
+        #[arg(long, help = "Sitenin PHP sürümü (örn: 8.3.10)")]
+        php: Option<String>,
+        #[arg(long, help = "Statik olmayan istekleri bu betiğe yönlendir (örn: index.php)")]
+        front_controller: Option<String>,
+        #[arg(long, help = "Sitenin PHP sunucusunun portu")]
+        port: Option<u16>,
+    },
+    #[command(about = "Kayıtlı siteleri, adreslerini ve durumlarını listeler")]
+    List,
+    #[command(about = "Siteyi durdurur ve config.toml'dan siler; proje dosyalarına dokunulmaz")]
+    Remove { name: String },
+    #[command(about = "Siteyi varsayılan tarayıcıda açar")]
+    Open { name: String },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ServeTarget {
+    Proxy,
+    Dns,
+}
+
+#[derive(Subcommand)]
+enum SslAction {
+    #[command(about = "Yerel kök sertifikaya güvenmek için gereken adımları gösterir ya da uygular")]
+    Trust {
+        #[arg(long, help = "Komutu göstermek yerine çalıştır (yönetici şifresi istenebilir)")]
+        install: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum HostsAction {
+    #[command(about = "Sitelerin alan adlarını hosts dosyasındaki FAMPP bloğuna yazar")]
+    Sync,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Toggle {
+    On,
+    Off,
+}
+
+#[derive(Subcommand)]
+enum IniAction {
+    #[command(about = "Bir ayarı değiştirir ve çalışan PHP servislerini yeniden başlatır")]
+    Set {
+        key: String,
+        value: String,
+        #[arg(long, help = "Ayarı yalnızca bu sitede geçerli kıl")]
+        site: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SupervisorAction {
+    Start,
+    Stop,
+}
+
+// Durum tablosunun yatay çizgilerini sütun genişliklerine göre çizer
+fn table_border(widths: &[usize], left: char, mid: char, right: char) -> String {
+    let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+    format!("{}{}{}", left, segments.join(&mid.to_string()), right)
+}
+
+// Port doluysa, `port_range` tanımlı değilken aranacak port sayısı
+const DEFAULT_PORT_SPAN: u16 = 100;
+// Servisin bağlandığı portu çıktısında bildirmesi için beklenecek en uzun süre
+const BIND_CONFIRM_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Bir servisi başlatmak için gereken her şey: komut tarifi ve (varsa) dinlenecek port
+struct Launch {
+    spec: LaunchSpec,
+    port: Option<u16>,
+    bind: String,
+}
+
+fn prepare_service(
+    config: &ConfigManager,
+    app_settings: &AppSettings,
+    i18n: &I18n,
+    svc: &dyn Service,
+    preferred_port: Option<u16>,
+    bind: Option<&str>,
+    attached: bool,
+) -> Option<Launch> {
+    let svc_settings = app_settings.service(svc.name());
+    let bind = bind
+        .map(str::to_string)
+        .or(svc_settings.bind.clone())
+        .unwrap_or_else(|| network::LOOPBACK.to_string());
+    let bin_path = match svc.binary(&config.base_path) {
+        Some(path) => path,
+        None => {
+            eprintln!("❌ Hata: '{}' bulunamadı. Lütfen önce kurulumu yapın.", svc.package());
+            return None;
+        }
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = std::fs::metadata(&bin_path) {
+            let mut perms = metadata.permissions();
+            perms.set_mode(0o755);
+            let _ = std::fs::set_permissions(&bin_path, perms);
+        }
+    }
+
+    let configured_port = svc.default_port(app_settings);
+    let mut actual_port = preferred_port.or(configured_port);
+
+    if svc.probe_free_port()
+        && let Some(start) = actual_port
+    {
+        let [low, high] = app_settings.service(svc.name()).port_range
+            .unwrap_or([start, start.saturating_add(DEFAULT_PORT_SPAN - 1)]);
+
+        // Aramaya tercih edilen porttan başla, aralığın sonuna gelince başa dön
+        let first = start.clamp(low, high.max(low));
+        let free = (first..=high)
+            .chain(low..first)
+            .find(|port| std::net::TcpListener::bind(network::socket_addr(&bind, *port)).is_ok());
+
+        let Some(port) = free else {
+            eprintln!("{} {} {}-{} ({})", "❌".red(), i18n.t("port_range_exhausted"), low, high, svc.name().bold());
+            eprintln!("   💡 {} {}", i18n.t("port_range_tip"), format!("[services.{}] port_range = [{}, {}]", svc.name(), low, high.saturating_add(DEFAULT_PORT_SPAN)).yellow());
+            return None;
+        };
+
+        if Some(port) != configured_port && preferred_port.is_none() {
+            println!("{} {} {} → {} ({})", "⚠️".yellow(), i18n.t("port_busy"), configured_port.unwrap_or_default().to_string().bold(), port.to_string().yellow().bold(), svc.name());
+        }
+        actual_port = Some(port);
+    }
+
+    let ctx = ServiceContext { base_path: &config.base_path, attached, bind: &bind, port: actual_port, i18n };
+
+    for dir in svc.data_dirs(&config.base_path) {
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir).unwrap();
+        }
+    }
+
+    if let Err(e) = svc.init(&ctx, &bin_path) {
+        eprintln!("❌ {}", e);
+        return None;
+    }
+
+    let mut args = svc.args(&ctx, &bin_path, actual_port);
+
+    // config.toml'daki [services.<ad>] bölümünden gelen ek argümanlar, ortam ve çalışma dizini
+    args.extend(svc_settings.args.iter().cloned());
+
+    let mut spec = LaunchSpec::new(&bin_path, args);
+    spec.cwd = svc_settings.cwd.as_deref().map(|cwd| config_path(&config.base_path, cwd));
+    spec.env.extend(svc.env(&ctx, actual_port));
+
+    if let Some(env_file) = &svc_settings.env_file {
+        match process::read_env_file(&config_path(&config.base_path, env_file)) {
+            Ok(vars) => spec.env.extend(vars),
+            Err(e) => {
+                eprintln!("{} .env: {}", "❌".red(), e);
+                return None;
+            }
+        }
+    }
+    spec.env.extend(svc_settings.env.clone());
+
+    Some(Launch { spec, port: actual_port, bind })
+}
+
+// Servisin gerçekte hangi porta bağlandığını, başlatmadan sonra log'a yazdıklarından okur
+fn confirm_bound_port<F>(svc: &dyn Service, pm: &ProcessManager, pkg: &str, log_offset: u64, still_alive: F) -> Option<u16>
+where
+    F: Fn() -> bool,
+{
+    use std::io::{Read, Seek, SeekFrom};
+
+    let deadline = std::time::Instant::now() + BIND_CONFIRM_TIMEOUT;
+
+    loop {
+        let mut output = String::new();
+        if let Ok(mut file) = std::fs::File::open(pm.log_path(pkg))
+            && file.seek(SeekFrom::Start(log_offset)).is_ok()
+        {
+            let mut bytes = Vec::new();
+            let _ = file.read_to_end(&mut bytes);
+            output = String::from_utf8_lossy(&bytes).to_string();
+        }
+
+        if let Some(port) = svc.bound_port(&output) {
+            return Some(port);
+        }
+        if !still_alive() || std::time::Instant::now() >= deadline {
+            return None;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+fn start_service(
+    config: &ConfigManager,
+    app_settings: &AppSettings,
+    i18n: &I18n,
+    pm: &ProcessManager,
+    pkg: &str,
+    preferred_port: Option<u16>,
+    bind: Option<&str>,
+) -> Option<u32> {
+    let Some(svc) = services::get(pkg, app_settings) else {
+        eprintln!("❌ Hata: '{}' paketi desteklenmiyor.", pkg);
+        return None;
+    };
+
+    let launch = prepare_service(config, app_settings, i18n, svc.as_ref(), preferred_port, bind, false)?;
+    let mut actual_port = launch.port;
+
+    println!("{} Booting {} engine...", "⚡".cyan(), pkg.bold().green());
+
+    // Port doğrulaması yalnızca bu başlatmanın çıktısına bakmalı
+    let log_offset = std::fs::metadata(pm.log_path(pkg)).map(|m| m.len()).unwrap_or(0);
+
+    match pm.start(pkg, &launch.spec) {
+        Ok(pid) => {
+            if let Some(port) = actual_port {
+                let _ = pm.save_port(pkg, port);
+            }
+            let _ = pm.save_bind(pkg, &launch.bind);
+
+            let still_alive = || pm.try_wait(pkg).is_none() && process::is_alive(pid);
+
+            // Port yoklaması ile servisin bağlanması arasında başka bir süreç portu kapmış olabilir
+            if svc.probe_free_port() {
+                match confirm_bound_port(svc.as_ref(), pm, pkg, log_offset, still_alive) {
+                    Some(bound) if Some(bound) != actual_port => {
+                        println!("{} {} {} → {}", "⚠️".yellow(), i18n.t("port_mismatch"), actual_port.unwrap_or_default(), bound.to_string().yellow().bold());
+                        let _ = pm.save_port(pkg, bound);
+                        actual_port = Some(bound);
+                    }
+                    None if !still_alive() => {
+                        pm.clear(pkg);
+                        eprintln!("{} Failed to start {}: {}", "❌".red().bold(), pkg.bold(), i18n.t("port_taken").red());
+                        eprintln!("   💡 {} {}", i18n.t("tip_monitor"), format!("'fampp logs {}'", pkg).yellow());
+                        return None;
+                    }
+                    _ => {}
+                }
+            }
+
+            let ready = health::wait_until_ready(
+                &pkg.to_uppercase(),
+                &svc.readiness(app_settings),
+                network::probe_host(&launch.bind),
+                actual_port.unwrap_or_default(),
+                Duration::from_secs(app_settings.service(pkg).ready_timeout),
+                still_alive,
+            );
+
+            match ready {
+                Ok(elapsed) => println!("{} {} ({:.1}s)", "💚".green(), i18n.t("ready"), elapsed.as_secs_f32()),
+                Err(reason) if !pm.is_running(pkg) => {
+                    pm.clear(pkg);
+                    eprintln!("{} Failed to start {}: {}", "❌".red().bold(), pkg.bold(), reason.red());
+                    eprintln!("   💡 {} {}", i18n.t("tip_monitor"), format!("'fampp logs {}'", pkg).yellow());
+                    return None;
+                }
+                Err(reason) => println!("{} {}: {}", "⚠️".yellow(), i18n.t("not_ready"), reason.yellow()),
+            }
+
+            println!("{} {} {} (PID: {})",
+                "✅".green(),
+                pkg.to_uppercase().bold(),
+                i18n.t("success_start"),
+                pid.to_string().yellow()
+            );
+
+            svc.print_endpoints(actual_port);
+            if let Some(port) = actual_port
+                && network::is_wildcard(&launch.bind)
+            {
+                for addr in network::lan_addresses() {
+                    println!("   {} {}", "📱 LAN :".magenta().bold(), network::socket_addr(&addr.to_string(), port));
                 }
             }
+            println!();
+            Some(pid)
+        }
+        Err(e) => {
+            eprintln!("{} Failed to start {}: {}", "❌".red().bold(), pkg.bold(), e.to_string().red());
+            None
+        }
+    }
+}
+
+// Servisi nazikçe durdurup aynı port ve adresle yeniden başlatır
+fn restart_service(
+    config: &ConfigManager,
+    app_settings: &AppSettings,
+    i18n: &I18n,
+    pm: &ProcessManager,
+    pkg: &str,
+) -> Option<u32> {
+    // Servisi durdurmadan önce eski portu okuyoruz ki aynı adreste geri gelsin
+    let previous_port = pm.read_port(pkg);
+    let previous_bind = pm.read_bind(pkg);
+
+    if pm.is_running(pkg) {
+        println!("{} {} {} engine...", "🔄".cyan(), i18n.t("restarting"), pkg.bold().cyan());
+
+        if let Err(e) = pm.stop(pkg, &services::shutdown_signal(pkg, app_settings)) {
+            eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow());
+            return None;
+        }
+    }
+
+    start_service(config, app_settings, i18n, pm, pkg, previous_port, previous_bind.as_deref())
+}
+
+// Üretilen yapılandırmayı (nginx, apache, php-fpm) config.toml'a göre yeniler ve servise yeniden yükleme sinyali gönderir
+fn reload_service(
+    config: &ConfigManager,
+    app_settings: &AppSettings,
+    i18n: &I18n,
+    pm: &ProcessManager,
+    pkg: &str,
+) {
+    let (svc, signal) = match services::get(pkg, app_settings)
+        .and_then(|svc| svc.reload_signal().map(str::to_string).map(|signal| (svc, signal)))
+    {
+        Some(found) => found,
+        None => {
+            eprintln!("{} {} {}", "⚠️".yellow(), pkg.bold(), i18n.t("reload_unsupported"));
+            return;
+        }
+    };
+
+    if let Some(bin_path) = svc.binary(&config.base_path) {
+        let bind = pm.read_bind(pkg).unwrap_or_else(|| network::LOOPBACK.to_string());
+        let ctx = ServiceContext { base_path: &config.base_path, attached: false, bind: &bind, port: pm.read_port(pkg), i18n };
+        if let Err(e) = svc.init(&ctx, &bin_path) {
+            eprintln!("{} {}", "⚠️".yellow(), e.yellow());
+        }
+    }
+
+    match pm.reload(pkg, &signal) {
+        Ok(_) => println!("{} {} {} (SIG{})", "✅".green(), pkg.to_uppercase().bold(), i18n.t("success_reload"), signal),
+        Err(e) => eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow()),
+    }
+}
+
+// Site adı servis adına (`php-<ad>`) ve alan adına girdiği için yalnızca küçük harf, rakam ve tire kabul edilir
+fn site_name(name: &str) -> Option<String> {
+    let name = name.to_lowercase();
+    let valid = !name.is_empty()
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    valid.then_some(name)
+}
+
+// Site ayarları değiştiğinde çalışan sunucuları günceller: sitenin PHP örneği yeni ayarlarla yeniden başlar,
+// nginx ve apache yapılandırmalarını yeniden üretir. Proxy ve DNS config.toml'ı kendileri izler.
+fn refresh_site(config: &ConfigManager, i18n: &I18n, name: &str) {
+    let app_settings = load_settings(config);
+    let pm = ProcessManager::new(&config.base_path);
+
+    let instance = PhpService::instance_name(name);
+    if app_settings.sites.contains_key(name) && pm.is_running(&instance) {
+        let bind = pm.read_bind(&instance);
+        println!("{} {} {} engine...", "🔄".cyan(), i18n.t("restarting"), instance.bold().cyan());
+
+        // Port değişmiş olabileceği için eski port yerine sitenin ayarı kullanılır
+        match pm.stop(&instance, &services::shutdown_signal(&instance, &app_settings)) {
+            Ok(_) => {
+                start_service(config, &app_settings, i18n, &pm, &instance, None, bind.as_deref());
+            }
+            Err(e) => eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow()),
+        }
+    }
+
+    for pkg in ["nginx", "apache"] {
+        if pm.is_running(pkg) {
+            reload_service(config, &app_settings, i18n, &pm, pkg);
+        }
+    }
+}
+
+// Sitenin PHP örneğini durdurur, kaydını config.toml'dan siler ve web sunucularını günceller
+fn remove_site(config: &ConfigManager, app_settings: &AppSettings, i18n: &I18n, name: &str) -> bool {
+    let pm = ProcessManager::new(&config.base_path);
+    let instance = PhpService::instance_name(name);
+    if pm.is_running(&instance) {
+        println!("{} {} {} engine...", "🛑".red(), i18n.t("halting"), instance.bold().cyan());
+        if let Err(e) = pm.stop(&instance, &services::shutdown_signal(&instance, app_settings)) {
+            eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow());
+        }
+    }
+
+    if let Err(e) = AppSettings::remove_site(&config.base_path, name) {
+        eprintln!("{} {}", "❌".red(), e.to_string().red());
+        return false;
+    }
+
+    println!("{} {} {}", "🗑️".cyan(), name.bold(), i18n.t("site_removed"));
+    refresh_site(config, i18n, name);
+    true
+}
+
+// Klasör adından site adı türetir (örn: "My_App.v2" -> "my-app-v2")
+fn site_slug(folder: &str) -> String {
+    folder
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// Sembolik bağlantılar ve `~/` farkları bir yana, iki yol aynı dizini mi gösteriyor?
+fn same_dir(a: &std::path::Path, b: &std::path::Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+// Sitenin tarayıcıdan açılacak adresi: proxy çalışıyorsa alan adı, değilse sitenin PHP sunucusu
+fn site_url(app_settings: &AppSettings, pm: &ProcessManager, name: &str, site: &SiteSettings) -> Option<String> {
+    if pm.is_running("proxy") {
+        let proxy = &app_settings.proxy;
+        let domain = site.domain(name, &proxy.tld);
+        let (scheme, port, default_port) = if proxy.https {
+            ("https", proxy.https_port, 443)
+        } else {
+            ("http", proxy.port, 80)
+        };
+        return Some(if port == default_port {
+            format!("{}://{}", scheme, domain)
+        } else {
+            format!("{}://{}:{}", scheme, domain, port)
+        });
+    }
+
+    let instance = PhpService::instance_name(name);
+    if !pm.is_running(&instance) {
+        return None;
+    }
+    pm.read_port(&instance).map(|port| format!("http://127.0.0.1:{}", port))
+}
+
+// Adresi sistemin varsayılan tarayıcısında açar
+fn open_in_browser(url: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+
+    command
+        .arg(url)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+// php.ini değişikliklerinin geçerli olması için çalışan PHP örneklerini (ya da yalnızca bir siteyi) yeniden başlatır
+fn restart_php(config: &ConfigManager, i18n: &I18n, site: Option<&str>) {
+    let app_settings = load_settings(config);
+    let pm = ProcessManager::new(&config.base_path);
+
+    let targets: Vec<String> = match site {
+        Some(site) => vec![PhpService::instance_name(site)],
+        None => services::all(&app_settings).iter()
+            .filter(|svc| svc.package().starts_with("php"))
+            .map(|svc| svc.name().to_string())
+            .collect(),
+    };
+
+    for pkg in targets.iter().filter(|pkg| pm.is_running(pkg)) {
+        restart_service(config, &app_settings, i18n, &pm, pkg);
+    }
+}
+
+// Eklenti ext/ dizininde yoksa registry'den indirir ve php.ini'de etkinleştirir
+async fn enable_php_extension(config: &ConfigManager, i18n: &I18n, name: &str) -> bool {
+    let binary = match phpext::find(&config.base_path, name) {
+        Some(binary) => binary,
+        None => {
+            let Some(info) = get_php_extension_info(name) else {
+                eprintln!("{} {} {}", "⚠️".yellow(), name.bold(), i18n.t("ext_unknown"));
+                return false;
+            };
+
+            let binary = phpext::binary(&config.base_path, &info.bin_name);
+            println!("{} Fetching {} from registry...", "📦".cyan(), info.name.bold().green());
+
+            if let Err(e) = std::fs::create_dir_all(phpext::dir(&config.base_path)) {
+                eprintln!("{} {}", "❌".red(), e.to_string().red());
+                return false;
+            }
+            if let Err(e) = downloader::download_file(&info.url, &binary).await {
+                let _ = std::fs::remove_file(&binary);
+                eprintln!("{} Download interrupted: {}", "❌".red(), e);
+                return false;
+            }
+            binary
+        }
+    };
+
+    match phpext::enable(&config.base_path, name, &binary) {
+        Ok(_) => {
+            println!("{} {} {}", "✅".green(), name.bold(), i18n.t("ext_now_enabled"));
+            true
+        }
+        Err(e) => {
+            eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+            false
+        }
+    }
+}
+
+// JSON çıktısı üretebilen komutlar: status, list, logs ve site list
+fn supports_json(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Status { .. } | Commands::List | Commands::Logs { .. } | Commands::Site { action: SiteAction::List }
+    )
+}
+
+// config.toml'u yükler; dosya bozuksa varsayılanlarla üzerine yazmak yerine hatayı gösterip çıkar.
+// Dil ayarı da bu dosyada olduğundan mesaj varsayılan dilde basılır.
+fn load_settings(config: &ConfigManager) -> AppSettings {
+    match AppSettings::load_or_create(&config.base_path) {
+        Ok(settings) => settings,
+        Err(e) => {
+            let i18n = I18n::new(&config.base_path, "en");
+            eprintln!("{} {} {}", "❌".red(), i18n.t("config_error").red().bold(), e.red());
+            std::process::exit(1);
+        }
+    }
+}
+
+// depends_on grafiğini kurar; döngü ya da bilinmeyen servis varsa yapılandırma hatası verip çıkar
+fn dependency_graph(app_settings: &AppSettings, i18n: &I18n) -> DependencyGraph {
+    match DependencyGraph::new(app_settings) {
+        Ok(graph) => graph,
+        Err(e) => {
+            eprintln!("{} {} {}", "❌".red(), i18n.t("config_error").red().bold(), e.red());
+            std::process::exit(1);
+        }
+    }
+}
+
+// Bağımlılık hazırlık yoklamasından geçene kadar bekler; zaten hazırsa hemen döner
+fn wait_for_dependency(app_settings: &AppSettings, pm: &ProcessManager, dep: &str) -> Result<(), String> {
+    let Some(svc) = services::get(dep, app_settings) else {
+        return Err(format!("'{}' is not a known service", dep));
+    };
+    if !pm.is_running(dep) {
+        return Err(format!("{} is not running", dep));
+    }
+
+    let port = pm.read_port(dep).or(svc.default_port(app_settings)).unwrap_or_default();
+    let bind = pm.read_bind(dep).unwrap_or_else(|| network::LOOPBACK.to_string());
+    health::wait_until_ready(
+        &dep.to_uppercase(),
+        &svc.readiness(app_settings),
+        network::probe_host(&bind),
+        port,
+        Duration::from_secs(app_settings.service(dep).ready_timeout),
+        || pm.is_running(dep),
+    )
+    .map(|_| ())
+}
+
+// Servisin bağımlılıklarından hazır olmayan varsa nedenini bildirir
+fn check_dependencies(app_settings: &AppSettings, pm: &ProcessManager, graph: &DependencyGraph, pkg: &str) -> Result<(), String> {
+    for dep in graph.dependencies(pkg) {
+        wait_for_dependency(app_settings, pm, dep).map_err(|e| format!("{}: {}", dep, e))?;
+    }
+    Ok(())
+}
+
+// `fampp up`'ı sonlandırması gereken ilk sinyali bekler; dinleyici kurulamazsa `false` döner
+async fn shutdown_requested() -> bool {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let (Ok(mut terminate), Ok(mut hangup)) = (signal(SignalKind::terminate()), signal(SignalKind::hangup())) else {
+            return tokio::signal::ctrl_c().await.is_ok();
+        };
+
+        tokio::select! {
+            result = tokio::signal::ctrl_c() => result.is_ok(),
+            _ = terminate.recv() => true,
+            _ = hangup.recv() => true,
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await.is_ok()
+    }
+}
+
+// `logs --json`: dosyaya eklenen her satırı `{"service": ..., "line": ...}` olarak ayrı bir JSON satırında basar
+fn stream_log_json(service: &str, log_file: &std::path::Path) -> ! {
+    use std::io::{BufRead, BufReader, Seek, SeekFrom};
+
+    let Ok(file) = std::fs::File::open(log_file) else {
+        std::process::exit(1);
+    };
+    let mut reader = BufReader::new(file);
+    let _ = reader.seek(SeekFrom::End(0));
+    let mut line = String::new();
+
+    loop {
+        // Yarım yazılmış satırlar tamamlanana kadar biriktirilir
+        match reader.read_line(&mut line) {
+            Ok(n) if n > 0 && line.ends_with('\n') => {
+                let doc = serde_json::json!({ "service": service, "line": line.trim_end_matches(['\r', '\n']) });
+                println!("{}", doc);
+                line.clear();
+            }
+            Ok(_) => thread::sleep(Duration::from_millis(300)),
+            Err(_) => std::process::exit(1),
+        }
+    }
+}
+
+// Durum tablosunu, çökme notlarını ve ipuçlarını basar (`status --watch` her saniye yeniden çağırır)
+fn print_status(
+    config: &ConfigManager,
+    i18n: &I18n,
+    pm: &ProcessManager,
+    stack: &StackStatus,
+    verbose: bool,
+) {
+    let active_rows: Vec<&ServiceStatus> = stack.services.iter().filter(|s| s.state != ServiceState::Stopped).collect();
+    let any_running = !active_rows.is_empty();
+
+    let widths = [12, 10, 7, 21, 10, 6, 9, 8, 9];
+    let v = "│".cyan();
+
+    println!("{}", table_border(&widths, '┌', '┬', '┐').cyan());
+
+    let h_svc = format!("{:<12}", i18n.t("service")).bold().cyan();
+    let h_stat = format!("{:<10}", i18n.t("status")).bold().cyan();
+    let h_pid = format!("{:<7}", "PID").bold().cyan();
+    let h_port = format!("{:<21}", i18n.t("port_info")).bold().cyan();
+    let h_health = format!("{:<10}", i18n.t("health")).bold().cyan();
+    let h_cpu = format!("{:>6}", "CPU").bold().cyan();
+    let h_mem = format!("{:>9}", i18n.t("memory")).bold().cyan();
+    let h_uptime = format!("{:>8}", i18n.t("uptime")).bold().cyan();
+    let h_children = format!("{:>9}", i18n.t("children")).bold().cyan();
+
+    println!("{} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {} {}",
+        v, h_svc, v, h_stat, v, h_pid, v, h_port, v, h_health, v, h_cpu, v, h_mem, v, h_uptime, v, h_children, v
+    );
+
+    println!("{}", table_border(&widths, '├', '┼', '┤').cyan());
+
+    if any_running {
+        for row in active_rows {
+            let info = row.port
+                .map(|p| network::socket_addr(row.host.as_deref().unwrap_or(network::LOOPBACK), p))
+                .unwrap_or_else(|| "-".to_string());
+
+            let c_svc = format!("{:<12}", row.name.to_uppercase()).bold();
+            let c_stat = if row.state == ServiceState::Crashed {
+                format!("{:<10}", i18n.t("state_crashed")).bold().red()
+            } else {
+                format!("{:<10}", i18n.t("active")).bold().green()
+            };
+            let c_pid = format!("{:<7}", row.pid.map(|p| p.to_string()).unwrap_or_default()).yellow();
+            let c_port = format!("{:<21}", info);
+            let c_health = if row.state == ServiceState::Running {
+                format!("{:<10}", i18n.t("healthy")).green()
+            } else {
+                format!("{:<10}", i18n.t("unhealthy")).red()
+            };
+
+            // Süreç tablosunda bulunamayan (örn: başka kullanıcıya ait) süreçler için "-"
+            let dash = || "-".to_string();
+            let cpu = row.cpu.map(|c| format!("{:.1}%", c)).unwrap_or_else(dash);
+            let mem = row.memory.map(stats::format_bytes).unwrap_or_else(dash);
+            let uptime = row.uptime.map(stats::format_duration).unwrap_or_else(dash);
+            let children = row.children.map(|c| c.to_string()).unwrap_or_else(dash);
+
+            println!("{} {} {} {} {} {} {} {} {} {} {} {:>6} {} {:>9} {} {:>8} {} {:>9} {}",
+                v, c_svc, v, c_stat, v, c_pid, v, c_port, v, c_health, v, cpu, v, mem, v, uptime, v, children, v
+            );
+        }
+    } else {
+        let msg = i18n.t("no_active_services");
+        let inner: usize = widths.iter().map(|w| w + 3).sum::<usize>() - 1;
+        let empty_msg = format!("{:^inner$}", msg).bold().red();
+        println!("{}{}{}", v, empty_msg, v);
+    }
+
+    println!("{}\n", table_border(&widths, '└', '┴', '┘').cyan());
+
+    if verbose {
+        for svc in stack.services.iter().map(|s| s.name.as_str()) {
+            if let Some(cmdline) = pm.read_command_line(svc) {
+                println!("{} {}", format!("▸ {}:", svc.to_uppercase()).cyan().bold(), cmdline.dimmed());
+            }
+        }
+        println!();
+    }
+
+    let mut notes = false;
+
+    if stack.supervisor {
+        println!("👁️  {}", i18n.t("supervisor_active").green());
+        notes = true;
+    }
+
+    for svc in stack.services.iter().map(|s| s.name.as_str()) {
+        let history = CrashHistory::load(&config.base_path, svc);
+        if let Some(last) = history.last() {
+            notes = true;
+            let code = last.exit_code.map(|c| c.to_string()).unwrap_or_else(|| "?".to_string());
+            let ago = supervisor::now_secs().saturating_sub(last.at);
+            println!("💥 {} {} {} {} (exit: {}, {}s ago) · {} {}",
+                svc.to_uppercase().bold(),
+                i18n.t("crashed"),
+                history.crashes.to_string().red().bold(),
+                i18n.t("times"),
+                code.yellow(),
+                ago,
+                i18n.t("restarts"),
+                history.restarts
+            );
         }
     }
-    None
+
+    if notes {
+        println!();
+    }
+
+    if any_running {
+        println!("💡 {} {}", i18n.t("tip_monitor"), "'fampp logs <service>'".yellow());
+    } else {
+        println!("💡 {} {}", i18n.t("tip_boot"), "'fampp start <service>'".yellow());
+    }
 }
 
 #[tokio::main]
@@ -76,11 +960,23 @@ async fn main() {
     let config = ConfigManager::new();
     config.init();
 
-    let app_settings = AppSettings::load_or_create(&config.base_path);
+    let app_settings = load_settings(&config);
     let i18n = I18n::new(&config.base_path, &app_settings.language);
 
+    // JSON çıktısına ANSI kaçış kodları karışmasın
+    if cli.json || cli.no_color {
+        colored::control::set_override(false);
+    }
+
+    let json = cli.json;
     let active_command = cli.command.unwrap_or(Commands::Help);
 
+    // --json sessizce yok sayılırsa betikler insan çıktısını ayrıştırmaya çalışır; desteklemeyen komutta reddet
+    if json && !supports_json(&active_command) {
+        eprintln!("{} {}", "❌".red(), i18n.t("json_unsupported").red());
+        std::process::exit(2);
+    }
+
     match active_command {
         Commands::Install { package, version } => {
             let v = version.as_deref().unwrap_or("latest");
@@ -96,6 +992,8 @@ async fn main() {
                         "zip"
                     };
                     
+                    // PHP'nin belirli bir sürümü varsayılanın yanına `packages/php-<sürüm>` olarak kurulur
+                    let package = if package == "php" { registry::php_package(version.as_deref()) } else { package };
                     let temp_file_path = config.base_path.join(format!("{}.{}", package, ext));
                     let package_dir = config.base_path.join("packages").join(&package);
 
@@ -130,128 +1028,151 @@ async fn main() {
                 }
             }
         }
-        Commands::Start { all: _, package } => {
+        Commands::Start { all, lan, site, root, router, front_controller, package } => {
             let pm = ProcessManager::new(&config.base_path);
-            
-            if let Some(pkg) = package {
-                match get_package_info(&pkg, "latest") {
-                    Some(info) => {
-                        let package_dir = config.base_path.join("packages").join(&pkg);
-                        
-                        let bin_path = match find_executable(&package_dir, &info.bin_name) {
-                            Some(path) => path,
-                            None => {
-                                eprintln!("❌ Hata: '{}' bulunamadı. Lütfen önce kurulumu yapın.", info.bin_name);
-                                return;
-                            }
-                        };
+            let mut package = package;
 
-                        #[cfg(unix)]
-                        {
-                            use std::os::unix::fs::PermissionsExt;
-                            if let Ok(metadata) = std::fs::metadata(&bin_path) {
-                                let mut perms = metadata.permissions();
-                                perms.set_mode(0o755);
-                                let _ = std::fs::set_permissions(&bin_path, perms);
-                            }
+            // `--site` ile adlandırılmış PHP örneği hedeflenir; `--root` verilmişse site önce kaydedilir
+            let app_settings = match site {
+                Some(requested) => {
+                    // Site adı PID/log dosya yollarına girdiği için `site add` ile aynı kurallar uygulanır
+                    let Some(site) = site_name(&requested) else {
+                        eprintln!("{} {} {}", "❌".red(), requested.bold(), i18n.t("site_invalid_name"));
+                        std::process::exit(1);
+                    };
+                    if services::site_name_taken(&site) {
+                        eprintln!("{} {} {} {}", "❌".red(), site.bold(), i18n.t("site_name_reserved"), PhpService::instance_name(&site).yellow());
+                        std::process::exit(1);
+                    }
+                    if package.as_deref().is_some_and(|pkg| !pkg.eq_ignore_ascii_case("php")) {
+                        eprintln!("{} {}", "⚠️".yellow(), i18n.t("site_php_only"));
+                        return;
+                    }
+
+                    if root.is_some() || router.is_some() || front_controller.is_some() {
+                        let mut entry = app_settings.sites.get(&site).cloned().unwrap_or_default();
+                        if let Some(root) = root {
+                            entry.root = std::path::absolute(&root).unwrap_or(root);
+                        }
+                        if let Some(router) = router {
+                            entry.router = Some(std::path::absolute(&router).unwrap_or(router));
+                        }
+                        if front_controller.is_some() {
+                            entry.front_controller = front_controller;
                         }
 
-                        let mut args: Vec<String> = Vec::new();
-                        let mut actual_port = app_settings.ports.php;
+                        if !expand_home(&entry.root).is_dir() {
+                            eprintln!("{} {} {}", "❌".red(), i18n.t("site_root_missing"), entry.root.display());
+                            return;
+                        }
 
-                        if pkg == "php" {
-                            let www_dir = config.base_path.join("www");
-                        
-                            while std::net::TcpListener::bind(("127.0.0.1", actual_port)).is_err() {
-                                actual_port += 1;
-                            }
+                        if let Err(e) = AppSettings::save_site(&config.base_path, &site, &entry) {
+                            eprintln!("{} {}", "❌".red(), e.to_string().red());
+                            return;
+                        }
+                        println!("{} {} {}", "📁".cyan(), site.bold(), i18n.t("site_saved"));
+                    } else if !app_settings.sites.contains_key(&site) {
+                        eprintln!("{} {} {}", "❌".red(), site.bold(), i18n.t("site_not_found"));
+                        eprintln!("   💡 {} {}", i18n.t("site_root_tip"), format!("'fampp start php --site {} --root <dir>'", site).yellow());
+                        return;
+                    }
 
-                            args = vec![
-                                "-S".to_string(),
-                                format!("127.0.0.1:{}", actual_port),
-                                "-t".to_string(),
-                                www_dir.to_str().unwrap().to_string(),
-                            ];
-                        } else if pkg == "mysql" {
-                            let db_data_dir = config.base_path.join("data").join("mysql");
-                            let actual_basedir = bin_path.parent().unwrap().parent().unwrap();
-                            
-                            if !db_data_dir.exists() {
-                                std::fs::create_dir_all(&db_data_dir).unwrap();
-                            }
+                    package = Some(PhpService::instance_name(&site));
+                    load_settings(&config)
+                }
+                None => app_settings,
+            };
 
-                            let is_empty = std::fs::read_dir(&db_data_dir).unwrap().next().is_none();
-                            if is_empty {
-                                println!("⏳ MySQL ilk kez hazırlanıyor (Sistem tabloları oluşturuluyor)...");
-                                let mut init_cmd = std::process::Command::new(&bin_path);
-                                init_cmd.arg("--initialize-insecure")
-                                        .arg(format!("--basedir={}", actual_basedir.to_str().unwrap()))
-                                        .arg(format!("--datadir={}", db_data_dir.to_str().unwrap()));
-                                
-                                let output = init_cmd.output().expect("❌ MySQL ilklendirilemedi!");
-                                if !output.status.success() {
-                                    eprintln!("❌ İlklendirme Hatası: {}", String::from_utf8_lossy(&output.stderr));
-                                    return;
-                                }
-                                println!("✅ MySQL veritabanı dosyaları başarıyla oluşturuldu.");
-                            }
+            let targets: Vec<String> = if all {
+                services::all(&app_settings).iter()
+                    .filter(|svc| svc.binary(&config.base_path).is_some() && !pm.is_running(svc.name()))
+                    .map(|svc| svc.name().to_string())
+                    .collect()
+            } else if let Some(pkg) = package {
+                vec![pkg]
+            } else {
+                println!("Lütfen bir paket adı belirtin (Örn: php veya mysql).");
+                return;
+            };
 
-                            let logs_dir = config.base_path.join("logs");
-                            if !logs_dir.exists() { std::fs::create_dir_all(&logs_dir).unwrap(); }
-                            
-                            let log_file = logs_dir.join("mysql.log");
-
-                            args = vec![
-                                format!("--basedir={}", actual_basedir.to_str().unwrap()),
-                                format!("--datadir={}", db_data_dir.to_str().unwrap()),
-                                "--port=3306".to_string(),
-                                format!("--log-error={}", log_file.to_str().unwrap()) // Hataları dosyaya yazdır!
-                            ];
-                        }
+            let graph = dependency_graph(&app_settings, &i18n);
+            let targets: Vec<String> = targets.iter().map(|pkg| pkg.to_lowercase()).collect();
 
-                        let args_str: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-                        
-                        println!("{} Booting {} engine...", "⚡".cyan(), pkg.bold().green());
-                        
-                        match pm.start(&pkg, &bin_path, &args_str) {
-                            Ok(pid) => {
-                                println!("{} {} {} (PID: {})", 
-                                    "✅".green(), 
-                                    pkg.to_uppercase().bold(), 
-                                    i18n.t("success_start"), 
-                                    pid.to_string().yellow()
-                                );
-                                
-                                if pkg == "php" {
-                                    println!("   {} http://127.0.0.1:{}", "🌐 Localhost :".cyan().bold(), actual_port);
-                                } else if pkg == "mysql" {
-                                    println!("   {} 127.0.0.1:3306", "🗄️  Host :".blue().bold());
-                                    println!("   {} root", "👤 User :".blue().bold());
-                                    println!("   {} (None)", "🔑 Pass :".blue().bold());
-                                }
-                                println!();
-                            }
-                            Err(e) => {
-                                eprintln!("{} Failed to start {}: {}", "❌".red().bold(), pkg.bold(), e.to_string().red());
-                            }
-                        }
-                    }
-                    None => eprintln!("❌ Hata: '{}' paketi desteklenmiyor.", pkg),
+            // Bağımlılıklar önce başlatılır ve hazır olmaları beklenir
+            for pkg in graph.start_order(&targets) {
+                if !targets.contains(&pkg) && pm.is_running(&pkg) {
+                    continue;
+                }
+
+                if let Err(reason) = check_dependencies(&app_settings, &pm, &graph, &pkg) {
+                    eprintln!("{} {} {} ({})", "⏭️".yellow(), pkg.bold(), i18n.t("dependency_not_ready"), reason.yellow());
+                    continue;
+                }
+
+                // --lan yalnızca açıkça istenen servislere uygulanır, bağımlılıklar loopback'te kalır
+                let bind = (lan && targets.contains(&pkg)).then_some(network::ALL_INTERFACES);
+                if start_service(&config, &app_settings, &i18n, &pm, &pkg, None, bind).is_some()
+                    && let Some((name, site)) = app_settings.sites.iter().find(|(name, _)| PhpService::instance_name(name) == pkg)
+                    && site.port.is_none()
+                    && let Some(port) = pm.read_port(&pkg)
+                {
+                    // İlk başlatmada bulunan portu siteye sabitle ki her seferinde aynı adreste açılsın
+                    let _ = AppSettings::save_site(&config.base_path, name, &SiteSettings { port: Some(port), ..site.clone() });
                 }
-            } else {
-                println!("Lütfen bir paket adı belirtin (Örn: php veya mysql).");
             }
         }
-        Commands::Stop { all: _, package } => {
+        Commands::Stop { all, cascade, package } => {
             let pm = ProcessManager::new(&config.base_path);
-            
-            if let Some(pkg) = package {
+
+            let targets: Vec<String> = if all {
+                services::all(&app_settings).iter()
+                    .filter(|svc| pm.read_pid(svc.name()).is_some())
+                    .map(|svc| svc.name().to_string())
+                    .collect()
+            } else if let Some(pkg) = package {
+                vec![pkg]
+            } else {
+                println!("{} {}", "⚠️".yellow(), i18n.t("specify_stop"));
+                return;
+            };
+
+            let graph = dependency_graph(&app_settings, &i18n);
+            let targets: Vec<String> = targets.iter().map(|pkg| pkg.to_lowercase()).collect();
+
+            // Bağımlı servisler, bağımlı oldukları servislerden önce durdurulur
+            let mut order: Vec<String> = graph.start_order(&targets).into_iter().filter(|pkg| targets.contains(pkg)).collect();
+            order.reverse();
+
+            let mut stop_list: Vec<String> = Vec::new();
+            for pkg in &order {
+                let dependents: Vec<String> = graph.dependents(pkg)
+                    .into_iter()
+                    .filter(|dep| !targets.contains(dep) && pm.is_running(dep))
+                    .collect();
+
+                if cascade {
+                    for dep in dependents {
+                        if !stop_list.contains(&dep) {
+                            stop_list.push(dep);
+                        }
+                    }
+                } else if !dependents.is_empty() {
+                    println!("{} {} {} {}", "⚠️".yellow(), pkg.bold(), i18n.t("required_by"), dependents.join(", ").bold());
+                    println!("   💡 {} {}", i18n.t("cascade_tip"), format!("'fampp stop {} --cascade'", pkg).yellow());
+                }
+
+                if !stop_list.contains(pkg) {
+                    stop_list.push(pkg.clone());
+                }
+            }
+
+            for pkg in stop_list {
                 println!("{} {} {} engine...", "🛑".red(), i18n.t("halting"), pkg.bold().cyan());
-                
-                match pm.stop(&pkg) {
+
+                match pm.stop(&pkg, &services::shutdown_signal(&pkg, &app_settings)) {
                     Ok(_) => {
-                        println!("{} {} {}", 
-                            "✅".green(), 
+                        println!("{} {} {}",
+                            "✅".green(),
                             pkg.to_uppercase().bold(),
                             i18n.t("success_stop")
                         );
@@ -260,96 +1181,256 @@ async fn main() {
                         eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow());
                     }
                 }
+            }
+        }
+        Commands::Restart { all, package } => {
+            let pm = ProcessManager::new(&config.base_path);
+
+            let targets: Vec<String> = if all {
+                services::all(&app_settings).iter()
+                    .filter(|svc| pm.read_pid(svc.name()).is_some())
+                    .map(|svc| svc.name().to_string())
+                    .collect()
+            } else if let Some(pkg) = package {
+                vec![pkg]
             } else {
-                println!("{} {}", "⚠️".yellow(), i18n.t("specify_stop"));
+                println!("{} {}", "⚠️".yellow(), i18n.t("specify_restart"));
+                return;
+            };
+
+            if targets.is_empty() {
+                println!("{} {}", "⚠️".yellow(), i18n.t("no_active_services"));
+                return;
+            }
+
+            for pkg in targets {
+                restart_service(&config, &app_settings, &i18n, &pm, &pkg);
             }
         }
-        Commands::Status => {
-            print!("{} {}", "🔍".cyan().bold(), i18n.t("status_fetching"));
-            io::stdout().flush().unwrap();
-            
+        Commands::Reload { package } => {
+            let pm = ProcessManager::new(&config.base_path);
+            reload_service(&config, &app_settings, &i18n, &pm, &package.to_lowercase());
+        }
+        Commands::Up { services: requested } => {
             let pm = ProcessManager::new(&config.base_path);
-            
-            let services = vec!["php", "mysql"];
 
-            let mut any_running = false;
-            let mut active_rows = Vec::new();
+            let targets: Vec<String> = if requested.is_empty() {
+                services::all(&app_settings).iter()
+                    .filter(|svc| svc.binary(&config.base_path).is_some())
+                    .map(|svc| svc.name().to_string())
+                    .collect()
+            } else {
+                requested.iter().map(|svc| svc.to_lowercase()).collect()
+            };
 
-            for svc in services {
-                let pid_file = pm.pids_dir.join(format!("{}.pid", svc));
-                
-                if pid_file.exists() {
-                    if let Ok(pid_content) = std::fs::read_to_string(&pid_file) {
+            if targets.is_empty() {
+                eprintln!("{} {}", "⚠️".yellow(), i18n.t("up_nothing_installed"));
+                std::process::exit(1);
+            }
 
-                        let port_file = pm.pids_dir.join(format!("{}.port", svc));
-                        let actual_port = if let Ok(port_str) = std::fs::read_to_string(&port_file) {
-                            port_str.trim().to_string() // Gerçek portu aldık!
-                        } else {
-                            // Eğer .port dosyası yoksa config.toml'daki varsayılana dön
-                            if svc == "php" {
-                                app_settings.ports.php.to_string()
-                            } else {
-                                app_settings.ports.mysql.to_string()
-                            }
-                        };
+            let graph = dependency_graph(&app_settings, &i18n);
+            let targets = graph.start_order(&targets);
 
-                        let info = format!("127.0.0.1:{}", actual_port);
+            let names: Vec<&str> = targets.iter().map(|s| s.as_str()).collect();
+            let mux = LogMux::new(&names);
 
-                        any_running = true;
-                        active_rows.push((
-                            svc.to_uppercase(),
-                            i18n.t("active"),
-                            pid_content.trim().to_string(),
-                            info,
-                        ));
+            // Ctrl+C, SIGTERM (systemd, `docker stop`) ya da SIGHUP (terminal kapandı) geldiğinde
+            // döngü servisleri nazikçe kapatıp çıkar; aksi halde çocuklar sahipsiz kalırdı
+            let shutdown = Arc::new(AtomicBool::new(false));
+            {
+                let shutdown = Arc::clone(&shutdown);
+                tokio::spawn(async move {
+                    if shutdown_requested().await {
+                        shutdown.store(true, Ordering::SeqCst);
                     }
+                });
+            }
+
+            let mut running: Vec<(String, Color)> = Vec::new();
+            let mut crashed = false;
+
+            for (i, svc) in targets.iter().enumerate() {
+                let color = LogMux::color_for(i);
+
+                if pm.is_running(svc) {
+                    mux.notice(svc, color, &format!("⚠️  {}", i18n.t("up_already_running")));
+                    continue;
                 }
+
+                let Some(service) = services::get(svc, &app_settings) else {
+                    mux.notice(svc, color, &format!("❌ '{}' paketi desteklenmiyor.", svc).red().to_string());
+                    crashed = true;
+                    continue;
+                };
+
+                if let Err(reason) = check_dependencies(&app_settings, &pm, &graph, svc) {
+                    mux.notice(svc, color, &format!("⏭️  {} ({})", i18n.t("dependency_not_ready"), reason).yellow().to_string());
+                    crashed = true;
+                    continue;
+                }
+
+                let Some(launch) = prepare_service(&config, &app_settings, &i18n, service.as_ref(), None, None, true) else {
+                    crashed = true;
+                    continue;
+                };
+                match pm.spawn_attached(svc, &launch.spec) {
+                    Ok((pid, stdout, stderr)) => {
+                        if let Some(port) = launch.port {
+                            let _ = pm.save_port(svc, port);
+                        }
+                        let _ = pm.save_bind(svc, &launch.bind);
+                        let log_path = pm.log_path(svc);
+                        mux.attach(svc, color, stdout, &log_path);
+                        mux.attach(svc, color, stderr, &log_path);
+                        let endpoint = launch.port.map(|p| format!(", {}", network::socket_addr(&launch.bind, p))).unwrap_or_default();
+                        mux.notice(svc, color, &format!("✅ {} (PID: {}{})", i18n.t("up_started"), pid, endpoint));
+                        running.push((svc.clone(), color));
+                    }
+                    Err(e) => {
+                        mux.notice(svc, color, &format!("❌ {}", e).red().to_string());
+                        crashed = true;
+                    }
+                }
+            }
+
+            if running.is_empty() {
+                std::process::exit(1);
+            }
+
+            println!("{} {}\n", "🛑".red(), i18n.t("log_exit_tip").dimmed());
+
+            while !running.is_empty() && !shutdown.load(Ordering::SeqCst) {
+                running.retain(|(svc, color)| match pm.try_wait(svc) {
+                    Some(status) => {
+                        if let Some(pid) = pm.read_pid(svc) {
+                            process::kill_tree(pid);
+                        }
+                        pm.clear(svc);
+                        if status.success() {
+                            mux.notice(svc, *color, &i18n.t("up_exited"));
+                        } else {
+                            crashed = true;
+                            mux.notice(svc, *color, &format!("💥 {} ({})", i18n.t("up_crashed"), status).red().to_string());
+                        }
+                        false
+                    }
+                    None => true,
+                });
+
+                tokio::time::sleep(Duration::from_millis(200)).await;
+            }
+
+            if !running.is_empty() {
+                println!("\n{} {}", "🛑".red(), i18n.t("up_stopping"));
+                // Bağımlılar önce kapansın diye başlatma sırasının tersine durdur
+                for (svc, color) in running.iter().rev() {
+                    let _ = pm.stop(svc, &services::shutdown_signal(svc, &app_settings));
+                    mux.notice(svc, *color, &i18n.t("success_stop"));
+                }
+            }
+
+            if crashed {
+                std::process::exit(1);
             }
+        }
+        Commands::Status { service, verbose, watch, quiet } => {
+            let pm = ProcessManager::new(&config.base_path);
+            let service = service.map(|svc| svc.to_lowercase());
 
-            thread::sleep(Duration::from_millis(150));
+            // Tek servis sorgusunda tablo ve JSON yalnızca o servisi içerir, çıkış kodu da ona göre belirlenir
+            let select = |mut stack: StackStatus| -> (StackStatus, i32) {
+                let Some(svc) = &service else {
+                    let code = stack.state.exit_code();
+                    return (stack, code);
+                };
+                stack.services.retain(|s| &s.name == svc);
+                // LSB: 4 = servis bilinmiyor
+                let code = stack.services.first().map(|s| s.state.exit_code()).unwrap_or(4);
+                (stack, code)
+            };
+
+            if quiet {
+                let (_, code) = select(stack_status::collect(&config.base_path, &app_settings, &pm, None));
+                std::process::exit(code);
+            }
+
+            if json {
+                let (stack, code) = select(stack_status::collect(&config.base_path, &app_settings, &pm, Some(&StatsCollector::new())));
+                println!("{}", serde_json::to_string_pretty(&stack).unwrap_or_default());
+                std::process::exit(code);
+            }
+
+            print!("{} {}", "🔍".cyan().bold(), i18n.t("status_fetching"));
+            io::stdout().flush().unwrap();
+
+            // İlk ölçüm CPU yüzdesi için kısa bir örnekleme aralığı bekler
+            let mut stats = StatsCollector::new();
 
             print!("\r\x1b[2K");
             io::stdout().flush().unwrap();
 
-            let v = "│".cyan(); 
-            
-            println!("{}", "┌──────────────┬──────────────┬─────────┬─────────────────────────┐".cyan());
-            
-            let h_svc = format!("{:<12}", i18n.t("service")).bold().cyan();
-            let h_stat = format!("{:<12}", i18n.t("status")).bold().cyan();
-            let h_pid = format!("{:<7}", "PID").bold().cyan();
-            let h_port = format!("{:<23}", i18n.t("port_info")).bold().cyan();
-            
-            println!("{} {} {} {} {} {} {} {} {}", 
-                v, h_svc, v, h_stat, v, h_pid, v, h_port, v
-            );
-            
-            println!("{}", "├──────────────┼──────────────┼─────────┼─────────────────────────┤".cyan());
-
-            if any_running {
-                for (svc, status, pid, info) in active_rows {
-                    let c_svc = format!("{:<12}", svc).bold();
-                    let c_stat = format!("{:<12}", status).bold().green();
-                    let c_pid = format!("{:<7}", pid).yellow();
-                    let c_port = format!("{:<23}", info);
-                    
-                    println!("{} {} {} {} {} {} {} {} {}", 
-                        v, c_svc, v, c_stat, v, c_pid, v, c_port, v
-                    );
-                }
-            } else {
-                let msg = i18n.t("no_active_services");
-                let empty_msg = format!("{:^65}", msg).bold().red();
-                println!("{}{}{}", v, empty_msg, v); 
+            if !watch {
+                let (stack, code) = select(stack_status::collect(&config.base_path, &app_settings, &pm, Some(&stats)));
+                print_status(&config, &i18n, &pm, &stack, verbose);
+                std::process::exit(code);
             }
 
-            println!("{}\n", "└──────────────┴──────────────┴─────────┴─────────────────────────┘".cyan());
+            loop {
+                let (stack, _) = select(stack_status::collect(&config.base_path, &app_settings, &pm, Some(&stats)));
+
+                // İmleci başa alıp ekranı temizleyerek tabloyu yerinde yeniden çiz
+                print!("\x1b[H\x1b[J");
+                print_status(&config, &i18n, &pm, &stack, verbose);
+                println!("\n{} {}", "🛑".red(), i18n.t("log_exit_tip").dimmed());
+                io::stdout().flush().unwrap();
+
+                thread::sleep(Duration::from_secs(1));
+                stats.refresh();
+            }
+        }
+        Commands::List => {
+            let packages: Vec<serde_json::Value> = registry::PACKAGES
+                .iter()
+                .map(|name| serde_json::json!({
+                    "name": name,
+                    "installed": registry::is_installed(&config.base_path, name),
+                    "available": get_package_info(name, "latest").is_some(),
+                }))
+                .collect();
 
-            if any_running {
-                println!("💡 {} {}", i18n.t("tip_monitor"), "'fampp logs <service>'".yellow());
+            if json {
+                let doc = serde_json::json!({ "packages": packages });
+                println!("{}", serde_json::to_string_pretty(&doc).unwrap_or_default());
+                return;
+            }
+
+            let widths = [12, 12, 12];
+            let v = "│".cyan();
+
+            println!("{}", table_border(&widths, '┌', '┬', '┐').cyan());
+            println!("{} {} {} {} {} {} {}",
+                v, format!("{:<12}", i18n.t("package")).bold().cyan(),
+                v, format!("{:<12}", i18n.t("installed")).bold().cyan(),
+                v, format!("{:<12}", i18n.t("available")).bold().cyan(), v
+            );
+            println!("{}", table_border(&widths, '├', '┼', '┤').cyan());
+
+            let mark = |flag: bool| if flag {
+                format!("{:<12}", "✔").green()
             } else {
-                println!("💡 {} {}", i18n.t("tip_boot"), "'fampp start <service>'".yellow());
+                format!("{:<12}", "✘").dimmed()
+            };
+
+            for package in &packages {
+                println!("{} {} {} {} {} {} {}",
+                    v, format!("{:<12}", package["name"].as_str().unwrap_or_default()).bold(),
+                    v, mark(package["installed"].as_bool().unwrap_or(false)),
+                    v, mark(package["available"].as_bool().unwrap_or(false)), v
+                );
             }
+
+            println!("{}\n", table_border(&widths, '└', '┴', '┘').cyan());
+            println!("💡 {} {}", i18n.t("tip_install"), "'fampp install <pkg>'".yellow());
         }
         Commands::Logs { package } => {
             let pkg = package.to_lowercase();
@@ -357,10 +1438,17 @@ async fn main() {
             let log_file = config.base_path.join("logs").join(format!("{}.log", pkg));
 
             if !log_file.exists() {
+                if json {
+                    std::process::exit(1);
+                }
                 eprintln!("{} {} {}", "⚠️".yellow(), i18n.t("log_not_found"), pkg.bold());
                 return;
             }
 
+            if json {
+                stream_log_json(&pkg, &log_file);
+            }
+
             // --- YENİ ŞIK LOG DASHBOARD TASARIMI ---
             println!("\n{}", "════════════════════════════════════════════════════════════════".cyan());
             println!(" {} {} {}", 
@@ -389,14 +1477,14 @@ async fn main() {
                     file.seek(SeekFrom::Start(pos)).unwrap();
                     buffer.clear();
                     
-                    if let Ok(bytes_read) = file.read_to_string(&mut buffer) {
-                        if bytes_read > 0 {
-                            // Logları göz yormaması için "bright_black" (Koyu Gri/Soluk) renkte basıyoruz
-                            print!("{}", buffer.bright_black());
-                            io::stdout().flush().unwrap();
-                            
-                            pos += bytes_read as u64;
-                        }
+                    if let Ok(bytes_read) = file.read_to_string(&mut buffer)
+                        && bytes_read > 0
+                    {
+                        // Logları göz yormaması için "bright_black" (Koyu Gri/Soluk) renkte basıyoruz
+                        print!("{}", buffer.bright_black());
+                        io::stdout().flush().unwrap();
+
+                        pos += bytes_read as u64;
                     }
                     
                     // Akıcı bir okuma için minik gecikme
@@ -406,6 +1494,506 @@ async fn main() {
                 eprintln!("{} {}", "❌".red(), i18n.t("log_read_error"));
             }
         }
+        Commands::Supervisor { action } => {
+            let pm = ProcessManager::new(&config.base_path);
+
+            match action {
+                SupervisorAction::Start => {
+                    if pm.is_running("supervisor") {
+                        println!("{} {}", "⚠️".yellow(), i18n.t("supervisor_already"));
+                        return;
+                    }
+
+                    let exe = std::env::current_exe().expect("❌ FAMPP çalıştırılabilir dosyası bulunamadı!");
+                    match pm.start("supervisor", &LaunchSpec::new(&exe, vec!["supervise".to_string()])) {
+                        Ok(pid) => println!("{} {} (PID: {})", "👁️".green(), i18n.t("supervisor_started"), pid.to_string().yellow()),
+                        Err(e) => eprintln!("{} {}", "❌".red(), e.to_string().red()),
+                    }
+                }
+                SupervisorAction::Stop => match pm.stop("supervisor", "TERM") {
+                    Ok(_) => println!("{} {}", "✅".green(), i18n.t("supervisor_stopped")),
+                    Err(e) => eprintln!("{} {}", "⚠️".yellow(), e.to_string().yellow()),
+                },
+            }
+        }
+        Commands::Php { command } => match command {
+            PhpCommand::Ini { action: IniAction::Set { key, value, site } } => {
+                let site = site.map(|s| s.to_lowercase());
+                match &site {
+                    Some(site) => {
+                        let Some(mut entry) = app_settings.sites.get(site).cloned() else {
+                            eprintln!("{} {} {}", "❌".red(), site.bold(), i18n.t("site_not_found"));
+                            return;
+                        };
+                        entry.ini.insert(key.clone(), value.clone());
+
+                        if let Err(e) = AppSettings::save_site(&config.base_path, site, &entry) {
+                            eprintln!("{} {}", "❌".red(), e.to_string().red());
+                            return;
+                        }
+                        println!("{} {} = {} ({})", "✅".green(), key.bold(), value.yellow(), site);
+                    }
+                    None => {
+                        match phpini::set(&config.base_path, &key, &value) {
+                            Ok(path) => println!("{} {} = {} ({})", "✅".green(), key.bold(), value.yellow(), path.display()),
+                            Err(e) => {
+                                eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+                                return;
+                            }
+                        }
+                    }
+                }
+
+                restart_php(&config, &i18n, site.as_deref());
+            }
+            PhpCommand::Ext { action: ExtAction::List } => {
+                let extensions = match phpext::list(&config.base_path, &PHP_EXTENSIONS) {
+                    Ok(extensions) => extensions,
+                    Err(e) => {
+                        eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+                        return;
+                    }
+                };
+
+                println!("\n{}", i18n.t("ext_list_title").bold().cyan());
+                for ext in extensions {
+                    let state = if ext.enabled {
+                        format!("✅ {}", i18n.t("ext_enabled")).green()
+                    } else if ext.installed {
+                        format!("💤 {}", i18n.t("ext_disabled")).yellow()
+                    } else {
+                        format!("📦 {}", i18n.t("ext_available")).dimmed()
+                    };
+                    println!("   {:<12} {}", ext.name.bold(), state);
+                }
+                println!();
+            }
+            PhpCommand::Ext { action: ExtAction::Enable { name } } => {
+                let name = name.to_lowercase();
+                if enable_php_extension(&config, &i18n, &name).await {
+                    restart_php(&config, &i18n, None);
+                }
+            }
+            PhpCommand::Ext { action: ExtAction::Disable { name } } => {
+                let name = name.to_lowercase();
+                match phpext::disable(&config.base_path, &name) {
+                    Ok(_) => println!("{} {} {}", "💤".yellow(), name.bold(), i18n.t("ext_now_disabled")),
+                    Err(e) => {
+                        eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+                        return;
+                    }
+                }
+                restart_php(&config, &i18n, None);
+            }
+            PhpCommand::Xdebug { state: Toggle::On } => {
+                if !enable_php_extension(&config, &i18n, "xdebug").await {
+                    return;
+                }
+
+                // Her istekte IDE'ye bağlanmayı dener; IDE dinlemiyorsa istek normal şekilde devam eder
+                for (key, value) in [("xdebug.mode", "debug,develop"), ("xdebug.start_with_request", "yes"), ("xdebug.client_port", "9003")] {
+                    if let Err(e) = phpini::set(&config.base_path, key, value) {
+                        eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+                        return;
+                    }
+                }
+
+                println!("{} {}", "🐞".green(), i18n.t("xdebug_on"));
+                restart_php(&config, &i18n, None);
+            }
+            PhpCommand::Xdebug { state: Toggle::Off } => {
+                // Eklenti tamamen kapatılır ki hata ayıklanmayan isteklerde yavaşlatmasın
+                if let Err(e) = phpini::set(&config.base_path, "xdebug.mode", "off")
+                    .and_then(|_| phpext::disable(&config.base_path, "xdebug"))
+                {
+                    eprintln!("{} php.ini: {}", "❌".red(), e.to_string().red());
+                    return;
+                }
+
+                println!("{} {}", "💤".yellow(), i18n.t("xdebug_off"));
+                restart_php(&config, &i18n, None);
+            }
+        },
+        Commands::Import { path } => {
+            let content = match std::fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    eprintln!("{} {}: {}", "❌".red(), path.display(), e.to_string().red());
+                    return;
+                }
+            };
+
+            let entries = services::parse_procfile(&content);
+            if entries.is_empty() {
+                eprintln!("{} {}", "⚠️".yellow(), i18n.t("import_empty"));
+                return;
+            }
+
+            // Procfile komutları kendi dizinine göreli yazılır; çalışma dizini olarak onu kaydet
+            let cwd = path
+                .canonicalize()
+                .ok()
+                .and_then(|p| p.parent().map(|dir| dir.to_string_lossy().to_string()))
+                .unwrap_or_else(|| ".".to_string());
+
+            let mut imported = Vec::new();
+
+            let result = AppSettings::update_config(&config.base_path, |table| {
+                let services_table = table
+                    .entry("services")
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                let Some(services_table) = services_table.as_table_mut() else {
+                    return;
+                };
+
+                for (name, command) in &entries {
+                    if is_reserved_service(name) {
+                        eprintln!("{} {} {}", "⚠️".yellow(), name.bold(), i18n.t("import_builtin_skipped"));
+                        continue;
+                    }
+
+                    let entry = services_table
+                        .entry(name.as_str())
+                        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+                    if let Some(entry) = entry.as_table_mut() {
+                        entry.insert("command".to_string(), toml::Value::String(command.clone()));
+                        entry.insert("cwd".to_string(), toml::Value::String(cwd.clone()));
+                        imported.push(name.clone());
+                    }
+                }
+            });
+
+            match result {
+                Ok(_) => {
+                    for name in &imported {
+                        println!("{} {} {}", "✅".green(), name.bold(), i18n.t("import_added"));
+                    }
+                }
+                Err(e) => eprintln!("{} {}", "❌".red(), e.to_string().red()),
+            }
+        }
+        Commands::Supervise => {
+            let pm = ProcessManager::new(&config.base_path);
+            let mut watcher = Supervisor::new(&config.base_path, &pm, &app_settings);
+
+            let names: Vec<String> = services::all(&app_settings).iter().map(|svc| svc.name().to_string()).collect();
+            let names: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
+
+            watcher.run(&names, |svc, port, bind| {
+                start_service(&config, &app_settings, &i18n, &pm, svc, port, bind)
+            });
+        }
+        Commands::Serve { server, bind, port, tls_port } => {
+            let result = match server {
+                ServeTarget::Proxy => proxy::serve(&config.base_path, &bind, port, tls_port).await,
+                ServeTarget::Dns => dns::serve(&config.base_path, &bind, port).await,
+            };
+
+            if let Err(e) = result {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Site { action } => match action {
+            SiteAction::Add { name, path, domain, php, front_controller, port } => {
+                let Some(name) = site_name(&name) else {
+                    eprintln!("{} {} {}", "❌".red(), name.bold(), i18n.t("site_invalid_name"));
+                    std::process::exit(1);
+                };
+                if services::site_name_taken(&name) {
+                    eprintln!("{} {} {} {}", "❌".red(), name.bold(), i18n.t("site_name_reserved"), PhpService::instance_name(&name).yellow());
+                    std::process::exit(1);
+                }
+
+                let root = std::path::absolute(&path).unwrap_or(path);
+                if !root.is_dir() {
+                    eprintln!("{} {} {}", "❌".red(), i18n.t("site_root_missing"), root.display());
+                    std::process::exit(1);
+                }
+
+                let mut entry = app_settings.sites.get(&name).cloned().unwrap_or_default();
+                entry.root = root;
+                if domain.is_some() {
+                    entry.domain = domain.map(|d| d.to_lowercase());
+                }
+                if php.is_some() {
+                    entry.php = php;
+                }
+                if front_controller.is_some() {
+                    entry.front_controller = front_controller;
+                }
+                if port.is_some() {
+                    entry.port = port;
+                }
+
+                if let Err(e) = AppSettings::save_site(&config.base_path, &name, &entry) {
+                    eprintln!("{} {}", "❌".red(), e.to_string().red());
+                    std::process::exit(1);
+                }
+
+                println!("{} {} {}", "📁".cyan(), name.bold(), i18n.t("site_saved"));
+                println!("   {} {}", "🌐 Domain :".cyan().bold(), entry.domain(&name, &app_settings.proxy.tld));
+                println!("   {} {}", "📂 Root :".cyan().bold(), entry.root.display());
+
+                let php_package = registry::php_package(entry.php.as_deref());
+                if !registry::is_installed(&config.base_path, &php_package) {
+                    println!("{} {} {}", "⚠️".yellow(), php_package.bold(), i18n.t("site_php_missing"));
+                    println!("   💡 {}", format!("'fampp install php --version {}'", entry.php.as_deref().unwrap_or("latest")).yellow());
+                }
+
+                refresh_site(&config, &i18n, &name);
+
+                let instance = PhpService::instance_name(&name);
+                if !ProcessManager::new(&config.base_path).is_running(&instance) {
+                    println!("💡 {} {}", i18n.t("site_start_tip"), format!("'fampp start {}'", instance).yellow());
+                }
+            }
+            SiteAction::List => {
+                let pm = ProcessManager::new(&config.base_path);
+
+                let sites: Vec<serde_json::Value> = app_settings.sites.iter()
+                    .map(|(name, site)| {
+                        let instance = PhpService::instance_name(name);
+                        serde_json::json!({
+                            "name": name,
+                            "domain": site.domain(name, &app_settings.proxy.tld),
+                            "root": expand_home(&site.root),
+                            "php": site.php.as_deref().unwrap_or(registry::PHP_VERSION),
+                            "port": pm.read_port(&instance).filter(|_| pm.is_running(&instance)).or(site.port),
+                            "running": pm.is_running(&instance),
+                            "url": site_url(&app_settings, &pm, name, site),
+                        })
+                    })
+                    .collect();
+
+                if json {
+                    let doc = serde_json::json!({ "sites": sites });
+                    println!("{}", serde_json::to_string_pretty(&doc).unwrap_or_default());
+                    return;
+                }
+
+                if sites.is_empty() {
+                    println!("{} {}", "⚠️".yellow(), i18n.t("site_none"));
+                    println!("💡 {} {}", i18n.t("site_root_tip"), "'fampp site add <name> <path>'".yellow());
+                    return;
+                }
+
+                let widths = [12, 24, 8, 6, 10];
+                let v = "│".cyan();
+
+                println!("{}", table_border(&widths, '┌', '┬', '┐').cyan());
+                println!("{} {} {} {} {} {} {} {} {} {} {}",
+                    v, format!("{:<12}", i18n.t("site")).bold().cyan(),
+                    v, format!("{:<24}", i18n.t("domain")).bold().cyan(),
+                    v, format!("{:<8}", "PHP").bold().cyan(),
+                    v, format!("{:<6}", i18n.t("port")).bold().cyan(),
+                    v, format!("{:<10}", i18n.t("status")).bold().cyan(), v
+                );
+                println!("{}", table_border(&widths, '├', '┼', '┤').cyan());
+
+                for site in &sites {
+                    let state = if site["running"].as_bool().unwrap_or(false) {
+                        format!("{:<10}", i18n.t("active")).green()
+                    } else {
+                        format!("{:<10}", i18n.t("stopped")).dimmed()
+                    };
+                    let port = site["port"].as_u64().map(|p| p.to_string()).unwrap_or_else(|| "-".to_string());
+
+                    println!("{} {} {} {:<24} {} {:<8} {} {:<6} {} {} {}",
+                        v, format!("{:<12}", site["name"].as_str().unwrap_or_default()).bold(),
+                        v, site["domain"].as_str().unwrap_or_default(),
+                        v, site["php"].as_str().unwrap_or_default(),
+                        v, port,
+                        v, state, v
+                    );
+                    println!("{} {} {}", " ".repeat(15), "↳".dimmed(), site["root"].as_str().unwrap_or_default().dimmed());
+                }
+
+                println!("{}\n", table_border(&widths, '└', '┴', '┘').cyan());
+                println!("💡 {} {}", i18n.t("site_open_tip"), "'fampp site open <name>'".yellow());
+            }
+            SiteAction::Remove { name } => {
+                let name = name.to_lowercase();
+                if !app_settings.sites.contains_key(&name) {
+                    eprintln!("{} {} {}", "❌".red(), name.bold(), i18n.t("site_not_found"));
+                    std::process::exit(1);
+                }
+
+                if !remove_site(&config, &app_settings, &i18n, &name) {
+                    std::process::exit(1);
+                }
+            }
+            SiteAction::Open { name } => {
+                let name = name.to_lowercase();
+                let Some(site) = app_settings.sites.get(&name) else {
+                    eprintln!("{} {} {}", "❌".red(), name.bold(), i18n.t("site_not_found"));
+                    std::process::exit(1);
+                };
+
+                let pm = ProcessManager::new(&config.base_path);
+                let Some(url) = site_url(&app_settings, &pm, &name, site) else {
+                    eprintln!("{} {} {}", "⚠️".yellow(), name.bold(), i18n.t("site_not_running"));
+                    eprintln!("   💡 {}", format!("'fampp start {}'", PhpService::instance_name(&name)).yellow());
+                    std::process::exit(1);
+                };
+
+                println!("{} {} {}", "🌍".cyan(), i18n.t("site_opening"), url.bold().underline());
+                if let Err(e) = open_in_browser(&url) {
+                    eprintln!("{} {} ({})", "⚠️".yellow(), i18n.t("site_open_failed"), e.to_string().yellow());
+                }
+            }
+        },
+        Commands::Link { name } => {
+            let cwd = match std::env::current_dir() {
+                Ok(cwd) => cwd,
+                Err(e) => {
+                    eprintln!("{} {}", "❌".red(), e.to_string().red());
+                    std::process::exit(1);
+                }
+            };
+
+            let folder = cwd.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            let requested = name.unwrap_or_else(|| site_slug(&folder));
+            let Some(name) = site_name(&requested) else {
+                eprintln!("{} {} {}", "❌".red(), requested.bold(), i18n.t("site_invalid_name"));
+                eprintln!("   💡 {}", "'fampp link --name <name>'".yellow());
+                std::process::exit(1);
+            };
+            if services::site_name_taken(&name) {
+                eprintln!("{} {} {} {}", "❌".red(), name.bold(), i18n.t("site_name_reserved"), PhpService::instance_name(&name).yellow());
+                eprintln!("   💡 {}", "'fampp link --name <name>'".yellow());
+                std::process::exit(1);
+            }
+
+            // public/ varsa docroot odur; içinde index.php varsa (Laravel, Symfony...) front controller olarak kullanılır
+            let public = cwd.join("public");
+            let (root, front_controller) = if public.is_dir() {
+                let front = public.join("index.php").is_file().then(|| "index.php".to_string());
+                (public, front)
+            } else {
+                (cwd.clone(), None)
+            };
+
+            if let Some(existing) = app_settings.sites.get(&name)
+                && !same_dir(&expand_home(&existing.root), &root)
+            {
+                eprintln!("{} {} {} {}", "❌".red(), name.bold(), i18n.t("site_name_taken"), existing.root.display());
+                eprintln!("   💡 {}", "'fampp link --name <name>'".yellow());
+                std::process::exit(1);
+            }
+
+            let mut entry = app_settings.sites.get(&name).cloned().unwrap_or_default();
+            entry.root = root;
+            if entry.front_controller.is_none() {
+                entry.front_controller = front_controller;
+            }
+
+            if let Err(e) = AppSettings::save_site(&config.base_path, &name, &entry) {
+                eprintln!("{} {}", "❌".red(), e.to_string().red());
+                std::process::exit(1);
+            }
+            println!("{} {} → {}", "🔗".cyan(), name.bold(), entry.root.display());
+
+            // Çalışıyorsa yeni ayarlarla yeniden başlar; değilse şimdi başlatılır
+            refresh_site(&config, &i18n, &name);
+
+            let app_settings = load_settings(&config);
+            let pm = ProcessManager::new(&config.base_path);
+            let instance = PhpService::instance_name(&name);
+            if !pm.is_running(&instance) {
+                if start_service(&config, &app_settings, &i18n, &pm, &instance, None, None).is_none() {
+                    std::process::exit(1);
+                }
+
+                // İlk başlatmada bulunan portu siteye sabitle ki her seferinde aynı adreste açılsın
+                if entry.port.is_none()
+                    && let Some(port) = pm.read_port(&instance)
+                {
+                    let _ = AppSettings::save_site(&config.base_path, &name, &SiteSettings { port: Some(port), ..entry.clone() });
+                }
+            }
+
+            if let Some(url) = site_url(&app_settings, &pm, &name, &entry) {
+                println!("{} {} {}", "🌍".green(), i18n.t("site_linked"), url.bold().underline());
+            }
+            if !pm.is_running("proxy") {
+                println!("💡 {} {} {}",
+                    entry.domain(&name, &app_settings.proxy.tld).bold(),
+                    i18n.t("site_proxy_tip"),
+                    "'fampp start proxy'".yellow()
+                );
+            }
+        }
+        Commands::Unlink { name } => {
+            // Ad verilmemişse kökü bu dizin (ya da bu dizinin public/ klasörü) olan site aranır
+            let name = match name {
+                Some(name) => Some(name.to_lowercase()),
+                None => std::env::current_dir().ok().and_then(|cwd| {
+                    app_settings.sites.iter()
+                        .find(|(_, site)| {
+                            let root = expand_home(&site.root);
+                            same_dir(&root, &cwd) || same_dir(&root, &cwd.join("public"))
+                        })
+                        .map(|(name, _)| name.clone())
+                }),
+            };
+
+            let Some(name) = name.filter(|name| app_settings.sites.contains_key(name)) else {
+                eprintln!("{} {}", "⚠️".yellow(), i18n.t("site_not_linked"));
+                eprintln!("   💡 {}", "'fampp site list'".yellow());
+                std::process::exit(1);
+            };
+
+            if !remove_site(&config, &app_settings, &i18n, &name) {
+                std::process::exit(1);
+            }
+        }
+        Commands::Ssl { action: SslAction::Trust { install } } => {
+            match ssl::ensure_ca(&config.base_path) {
+                Ok(true) => println!("{} {}", "🔐".green(), i18n.t("ssl_ca_created")),
+                Ok(false) => {}
+                Err(e) => {
+                    eprintln!("{} {}", "❌".red(), e.to_string().red());
+                    return;
+                }
+            }
+            println!("   {} {}", i18n.t("ssl_ca_path").bold(), ssl::ca_path(&config.base_path).display());
+
+            let command = ssl::trust_command(&config.base_path);
+            if !install {
+                println!("\n{}", i18n.t("ssl_trust_hint"));
+                println!("   {}", command.yellow());
+                println!("\n{} {}", "💡".yellow(), i18n.t("ssl_firefox"));
+                return;
+            }
+
+            let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+            match std::process::Command::new(shell).args([flag, command.as_str()]).status() {
+                Ok(status) if status.success() => println!("{} {}", "✅".green(), i18n.t("ssl_trusted")),
+                Ok(_) => eprintln!("{} {}", "❌".red(), i18n.t("ssl_trust_failed")),
+                Err(e) => eprintln!("{} {}: {}", "❌".red(), i18n.t("ssl_trust_failed"), e),
+            }
+        }
+        Commands::Hosts { action: HostsAction::Sync } => {
+            let domains: Vec<String> = proxy::routes(&app_settings).into_keys().collect();
+            let address = &app_settings.dns.address;
+
+            match hosts::sync(address, &domains) {
+                Ok(path) if domains.is_empty() => println!("{} {} ({})", "✅".green(), i18n.t("hosts_cleared"), path.display()),
+                Ok(path) => {
+                    println!("{} {} ({})", "✅".green(), i18n.t("hosts_synced"), path.display());
+                    for domain in &domains {
+                        println!("   {} {}", address.dimmed(), domain.bold());
+                    }
+                }
+                // Hosts dosyası yalnızca yönetici tarafından yazılabilir; bloğu elle eklemek için göster
+                Err(e) if e.kind() == io::ErrorKind::PermissionDenied => {
+                    eprintln!("{} {} ({})", "⚠️".yellow(), i18n.t("hosts_permission"), hosts::path().display());
+                    print!("\n{}", hosts::render_block(address, &domains));
+                }
+                Err(e) => eprintln!("{} {}: {}", "❌".red(), hosts::path().display(), e.to_string().red()),
+            }
+        }
         Commands::Help => {
             let ascii_logo = r#"
  ________  ______   __       __  _______   _______  
@@ -432,15 +2020,31 @@ $$/      $$/   $$/ $$/      $$/ $$/       $$/
 
             let commands = vec![
                 ("install <pkg>", i18n.t("cmd_install")),
-                ("start <pkg>", i18n.t("cmd_start")),
-                ("stop <pkg>", i18n.t("cmd_stop")),
-                ("status", i18n.t("cmd_status")),
+                ("start <pkg> [--lan]", i18n.t("cmd_start")),
+                ("start php --site <name>", i18n.t("cmd_start_site")),
+                ("stop <pkg> [--cascade]", i18n.t("cmd_stop")),
+                ("restart <pkg>", i18n.t("cmd_restart")),
+                ("reload <pkg>", i18n.t("cmd_reload")),
+                ("up [svc...]", i18n.t("cmd_up")),
+                ("status [svc] [--watch]", i18n.t("cmd_status")),
+                ("list", i18n.t("cmd_list")),
                 ("logs <pkg>", i18n.t("cmd_logs")),
+                ("supervisor <start|stop>", i18n.t("cmd_supervisor")),
+                ("php ini set <key> <val>", i18n.t("cmd_php_ini")),
+                ("php ext list|enable|disable", i18n.t("cmd_php_ext")),
+                ("php xdebug on|off", i18n.t("cmd_php_xdebug")),
+                ("site add <name> <path>", i18n.t("cmd_site_add")),
+                ("site list|remove|open", i18n.t("cmd_site")),
+                ("link [--name <name>]", i18n.t("cmd_link")),
+                ("unlink", i18n.t("cmd_unlink")),
+                ("hosts sync", i18n.t("cmd_hosts_sync")),
+                ("ssl trust [--install]", i18n.t("cmd_ssl_trust")),
+                ("import [Procfile]", i18n.t("cmd_import")),
                 ("help", i18n.t("cmd_help")),
             ];
 
             for (cmd, desc) in commands {
-                println!("  {:<15} {}", cmd.green().bold(), desc);
+                println!("  {:<25} {}", cmd.green().bold(), desc);
             }
             
             println!(); 
@@ -0,0 +1,54 @@
+<?php
+// FAMPP router script for PHP's built-in server.
+// Managed by FAMPP: this file is rewritten every time a site starts, do not edit it in place.
+//
+// Static files are served as-is, existing .php scripts run directly and everything else
+// goes through the site's front controller (FAMPP_FRONT_CONTROLLER, e.g. "index.php").
+
+$docroot = rtrim($_SERVER['DOCUMENT_ROOT'], '/');
+$path = rawurldecode(parse_url($_SERVER['REQUEST_URI'], PHP_URL_PATH) ?: '/');
+$front = '/' . ltrim(getenv('FAMPP_FRONT_CONTROLLER') ?: 'index.php', '/');
+
+// Never let "../" escape the document root
+if (preg_match('#(^|/)\.\.(/|$)#', $path)) {
+    http_response_code(400);
+    return true;
+}
+
+// 1. Static assets (css, js, images...) are handed back to the built-in server
+if ($path !== '/' && is_file($docroot . $path) && !str_ends_with($path, '.php')) {
+    return false;
+}
+
+// 2. A real script, optionally followed by PATH_INFO: /report.php/2024/05
+$script = null;
+$pathInfo = '';
+if (preg_match('#^(.+?\.php)(/.*)?$#', $path, $m) && is_file($docroot . $m[1])) {
+    $script = $m[1];
+    $pathInfo = $m[2] ?? '';
+} elseif (is_dir($docroot . $path) && is_file($docroot . rtrim($path, '/') . '/index.php')) {
+    $script = rtrim($path, '/') . '/index.php';
+}
+
+// 3. Everything else is routed through the front controller
+if ($script === null) {
+    if (!is_file($docroot . $front)) {
+        http_response_code(404);
+        echo "FAMPP: front controller {$front} not found in {$docroot}\n";
+        return true;
+    }
+    $script = $front;
+    $pathInfo = $path === $front ? '' : $path;
+}
+
+$_SERVER['SCRIPT_NAME'] = $script;
+$_SERVER['SCRIPT_FILENAME'] = $docroot . $script;
+$_SERVER['PHP_SELF'] = $script . $pathInfo;
+if ($pathInfo !== '') {
+    $_SERVER['PATH_INFO'] = $pathInfo;
+} else {
+    unset($_SERVER['PATH_INFO']);
+}
+
+chdir(dirname($_SERVER['SCRIPT_FILENAME']));
+require $_SERVER['SCRIPT_FILENAME'];